@@ -37,9 +37,16 @@ async fn main() {
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
+    // Storage first: the policy keeps daily velocity usage in it
+    utils::storage::init_storage().expect("opening storage failed");
+    utils::policy::init_policy().expect("loading policy config failed");
+    modules::pay_request::init_pay_templates().expect("loading pay templates failed");
+    modules::invoice::spawn_invoice_poller();
+    modules::webhook::spawn_webhook_poller();
+
     let cors = CorsLayer::new()
         .allow_origin(Any)
-        .allow_methods([Method::GET, Method::POST])
+        .allow_methods([Method::GET, Method::POST, Method::DELETE])
         .allow_headers(Any);
 
     let app = Router::new()
@@ -47,7 +54,19 @@ async fn main() {
         .merge(modules::keypair::routes())
         .merge(modules::token::routes())
         .merge(modules::message::routes())
+        .merge(modules::memo::routes())
         .merge(modules::send::routes())
+        .merge(modules::nonce::routes())
+        .merge(modules::pay::routes())
+        .merge(modules::pay_request::routes())
+        .merge(modules::invoice::routes())
+        .merge(modules::webhook::routes())
+        .merge(modules::stream::routes())
+        .merge(modules::stake::routes())
+        .merge(modules::validators::routes())
+        .merge(modules::wallet::routes())
+        .merge(modules::account::routes())
+        .merge(modules::airdrop::routes())
         .fallback(handle_404)
         .layer(
             TraceLayer::new_for_http()
@@ -0,0 +1,257 @@
+use crate::utils::amount::{format_ui_amount, SOL_DECIMALS};
+use crate::utils::errors::SolanaError;
+use crate::utils::pubkey::parse_pubkey;
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::Path,
+    routing::{get, post},
+    Json, Router,
+};
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use solana_account_decoder::parse_account_data::{
+    parse_account_data, AccountAdditionalData, ParseAccountError,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+// getMultipleAccounts accepts at most 100 addresses
+const MAX_ACCOUNTS: usize = 100;
+
+#[derive(Deserialize, Serialize)]
+pub struct AccountsRequest {
+    pub addresses: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+pub struct AccountInfoResponse {
+    pub address: String,
+    pub lamports: u64,
+    pub sol: String,
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data_len: usize,
+    pub data: String, // Base64 encoded
+    // Set when the owner is a program with a known account layout
+    pub program: Option<String>,
+    pub parsed: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct AccountsResponse {
+    // In request order; null for accounts that do not exist
+    pub accounts: Vec<Option<AccountInfoResponse>>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/account/:address", get(get_account))
+        .route("/accounts", post(get_accounts))
+}
+
+fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+fn decode_account(
+    address: &Pubkey,
+    account: &Account,
+    decimals: Option<u8>,
+) -> Result<(String, serde_json::Value), ParseAccountError> {
+    let parsed = parse_account_data(
+        address,
+        &account.owner,
+        &account.data,
+        Some(AccountAdditionalData {
+            spl_token_decimals: decimals,
+        }),
+    )?;
+
+    Ok((parsed.program, parsed.parsed))
+}
+
+// Token accounts can only be decoded with their mint's decimals; returns the mint to fetch
+fn required_mint(address: &Pubkey, account: &Account) -> Option<Pubkey> {
+    if !is_token_program(&account.owner) {
+        return None;
+    }
+
+    match decode_account(address, account, None) {
+        // The mint is the first field of a token account
+        Err(ParseAccountError::AdditionalDataMissing(_)) => account
+            .data
+            .get(..32)
+            .and_then(|mint| Pubkey::try_from(mint).ok()),
+        _ => None,
+    }
+}
+
+fn fetch_mint_decimals(
+    client: &RpcClient,
+    accounts: &[(Pubkey, Option<Account>)],
+) -> Result<HashMap<Pubkey, u8>, SolanaError> {
+    let mints: Vec<Pubkey> = accounts
+        .iter()
+        .filter_map(|(address, account)| required_mint(address, account.as_ref()?))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    if mints.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mint_accounts = client.get_multiple_accounts(&mints)?;
+
+    Ok(mints
+        .into_iter()
+        .zip(mint_accounts)
+        .filter_map(|(mint, account)| {
+            let decimals = StateWithExtensions::<Mint>::unpack(&account?.data)
+                .ok()?
+                .base
+                .decimals;
+            Some((mint, decimals))
+        })
+        .collect())
+}
+
+fn account_response(
+    address: &Pubkey,
+    account: Account,
+    mint_decimals: &HashMap<Pubkey, u8>,
+) -> AccountInfoResponse {
+    let decimals =
+        required_mint(address, &account).and_then(|mint| mint_decimals.get(&mint).copied());
+
+    // Plain wallets are System accounts without data; nothing to decode
+    let decoded = (!account.data.is_empty()).then(|| decode_account(address, &account, decimals));
+
+    let (program, parsed, parse_error) = match decoded {
+        Some(Ok((program, parsed))) => (Some(program), Some(parsed), None),
+        // Accounts of unknown programs are returned raw
+        None | Some(Err(ParseAccountError::ProgramNotParsable)) => (None, None, None),
+        Some(Err(e)) => (None, None, Some(e.to_string())),
+    };
+
+    AccountInfoResponse {
+        address: address.to_string(),
+        lamports: account.lamports,
+        sol: format_ui_amount(account.lamports, SOL_DECIMALS),
+        owner: account.owner.to_string(),
+        executable: account.executable,
+        rent_epoch: account.rent_epoch,
+        data_len: account.data.len(),
+        data: general_purpose::STANDARD.encode(&account.data),
+        program,
+        parsed,
+        parse_error,
+    }
+}
+
+fn fetch_accounts(
+    client: &RpcClient,
+    addresses: &[Pubkey],
+) -> Result<Vec<Option<AccountInfoResponse>>, SolanaError> {
+    let accounts = client
+        .get_multiple_accounts_with_commitment(addresses, CommitmentConfig::confirmed())?
+        .value;
+
+    let accounts: Vec<(Pubkey, Option<Account>)> =
+        addresses.iter().copied().zip(accounts).collect();
+    let mint_decimals = fetch_mint_decimals(client, &accounts)?;
+
+    Ok(accounts
+        .into_iter()
+        .map(|(address, account)| {
+            account.map(|account| account_response(&address, account, &mint_decimals))
+        })
+        .collect())
+}
+
+async fn get_account(Path(address): Path<String>) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /account/{}", address);
+
+    let account_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid account address".to_string()))?;
+
+    let client = get_rpc_client();
+
+    let account = fetch_accounts(&client, &[account_pubkey])?
+        .pop()
+        .flatten()
+        .ok_or_else(|| SolanaError::InvalidInput("Account not found".to_string()))?;
+
+    info!(
+        "Account {} owned by {} ({} bytes, decoded as {})",
+        account.address,
+        account.owner,
+        account.data_len,
+        account.program.as_deref().unwrap_or("raw")
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": account
+    });
+
+    info!("Response: 200 - Account fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_accounts(
+    Json(payload): Json<AccountsRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /accounts - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let addresses = payload
+        .addresses
+        .as_ref()
+        .filter(|addresses| !addresses.is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    if addresses.len() > MAX_ACCOUNTS {
+        return Err(SolanaError::InvalidInput(format!(
+            "At most {MAX_ACCOUNTS} addresses are allowed"
+        )));
+    }
+
+    // Parse public keys AFTER validation
+    let account_pubkeys = addresses
+        .iter()
+        .map(|address| parse_pubkey(Some(address), "account"))
+        .collect::<Result<Vec<Pubkey>, SolanaError>>()?;
+
+    let client = get_rpc_client();
+
+    let accounts = fetch_accounts(&client, &account_pubkeys)?;
+
+    info!(
+        "Fetched {} accounts, {} found",
+        accounts.len(),
+        accounts.iter().flatten().count()
+    );
+
+    let response = AccountsResponse { accounts };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Accounts fetched successfully");
+
+    Ok(Json(json_response))
+}
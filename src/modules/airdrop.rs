@@ -0,0 +1,957 @@
+use crate::modules::send::{plan_batch, Payout, PlannedTransaction};
+use crate::modules::token::fetch_mint;
+use crate::utils::amount::{format_ui_amount, parse_ui_amount, SOL_DECIMALS};
+use crate::utils::errors::SolanaError;
+use crate::utils::policy::{api_key, policy, Transfer};
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_client::client_error::ClientErrorKind;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcError;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::hashv,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::state::{Account, Mint};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use std::{env, thread};
+use tracing::{info, warn};
+
+// Base fee charged per signature; every airdrop transaction has a single signer
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+const MAX_SEND_ATTEMPTS: usize = 3;
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Deserialize, Serialize)]
+pub struct AirdropPlanRequest {
+    pub sender: Option<String>,
+    // Omitted for SOL airdrops
+    pub mint: Option<String>,
+    // Rows of `address,amount` with amounts in UI units
+    pub csv: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AirdropExecuteRequest {
+    pub secret: Option<String>, // Base58 encoded sender secret key
+    pub mint: Option<String>,
+    pub csv: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RowError {
+    pub line: u64,
+    pub address: String,
+    pub amount: String,
+    pub error: String,
+}
+
+#[derive(Serialize)]
+pub struct AirdropPlanResponse {
+    pub airdrop_id: String,
+    pub sender: String,
+    pub mint: Option<String>,
+    pub decimals: u8,
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub invalid_rows: Vec<RowError>,
+    pub total_amount: u64,
+    pub total_ui_amount: String,
+    pub ata_creations: usize,
+    pub ata_rent_lamports: u64,
+    pub transaction_count: usize,
+    pub fee_lamports: u64,
+    // SOL the sender needs: rent and fees, plus the airdrop itself for SOL airdrops
+    pub required_lamports: u64,
+    pub sender_lamports: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_token_amount: Option<u64>,
+    pub sufficient_funds: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AirdropStatus {
+    Running,
+    Completed,
+    Failed,
+    // Found in the journal but not running, e.g. after a restart
+    Interrupted,
+}
+
+#[derive(Clone, Serialize)]
+pub struct AirdropProgress {
+    pub airdrop_id: String,
+    pub status: AirdropStatus,
+    pub total_batches: usize,
+    pub confirmed_batches: usize,
+    pub total_recipients: usize,
+    pub paid_recipients: usize,
+    pub signatures: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct AirdropState {
+    progress: Arc<Mutex<HashMap<String, AirdropProgress>>>,
+}
+
+// Append-only record of what was sent; replayed to resume without double paying
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum JournalEntry {
+    Planned {
+        batches: Vec<Vec<usize>>,
+    },
+    Sent {
+        batch: usize,
+        signature: String,
+        last_valid_block_height: u64,
+    },
+    Confirmed {
+        batch: usize,
+        signature: String,
+    },
+    Failed {
+        batch: usize,
+        signature: String,
+        error: String,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum BatchState {
+    Pending,
+    Sent {
+        signature: Signature,
+        last_valid_block_height: u64,
+    },
+    Confirmed(Signature),
+}
+
+enum Outcome {
+    Landed,
+    Failed(String),
+    Expired,
+}
+
+struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    fn open(airdrop_id: &str) -> Self {
+        let dir = env::var("AIRDROP_JOURNAL_DIR").unwrap_or_else(|_| "airdrops".to_string());
+        Self {
+            path: PathBuf::from(dir).join(format!("{airdrop_id}.jsonl")),
+        }
+    }
+
+    fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    fn load(&self) -> Result<Vec<JournalEntry>, SolanaError> {
+        if !self.exists() {
+            return Ok(Vec::new());
+        }
+
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|e| SolanaError::StorageError(format!("Failed to read journal: {e}")))?;
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|e| SolanaError::StorageError(format!("Corrupt journal entry: {e}")))
+            })
+            .collect()
+    }
+
+    fn append(&self, entry: &JournalEntry) -> Result<(), SolanaError> {
+        let storage_error =
+            |e: std::io::Error| SolanaError::StorageError(format!("Failed to write journal: {e}"));
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(storage_error)?;
+        }
+
+        let mut line = serde_json::to_string(entry)
+            .map_err(|e| SolanaError::StorageError(format!("Failed to encode journal: {e}")))?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(storage_error)?;
+
+        file.write_all(line.as_bytes()).map_err(storage_error)?;
+        // The entry must be durable before the transaction it describes is sent
+        file.sync_data().map_err(storage_error)
+    }
+}
+
+struct AirdropPlan {
+    mint: Option<Pubkey>,
+    transfers: Vec<Transfer>,
+    batches: Vec<PlannedTransaction>,
+    report: AirdropPlanResponse,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/airdrop/plan", post(plan_airdrop))
+        .route("/airdrop/execute", post(execute_airdrop))
+        .route("/airdrop/:id", get(get_airdrop_progress))
+        .with_state(AirdropState::default())
+}
+
+fn parse_keypair(secret: &str) -> Result<Keypair, SolanaError> {
+    let secret_bytes = bs58::decode(secret)
+        .into_vec()
+        .map_err(|_| SolanaError::InvalidInput("Invalid secret key format".to_string()))?;
+
+    if secret_bytes.len() != 64 {
+        return Err(SolanaError::InvalidInput(
+            "Invalid secret key length".to_string(),
+        ));
+    }
+
+    Keypair::from_bytes(&secret_bytes)
+        .map_err(|_| SolanaError::InvalidInput("Invalid secret key".to_string()))
+}
+
+// Size of a new associated token account for the mint, including required extensions
+fn token_account_len(client: &RpcClient, mint: &Pubkey, program_id: &Pubkey) -> usize {
+    if *program_id != spl_token_2022::id() {
+        return Account::LEN;
+    }
+
+    let mut extensions = client
+        .get_account_data(mint)
+        .ok()
+        .and_then(|data| {
+            StateWithExtensions::<Mint>::unpack(&data)
+                .ok()
+                .and_then(|state| state.get_extension_types().ok())
+        })
+        .map(|types| ExtensionType::get_required_init_account_extensions(&types))
+        .unwrap_or_default();
+
+    // The associated token program always adds ImmutableOwner on Token-2022
+    extensions.push(ExtensionType::ImmutableOwner);
+
+    ExtensionType::try_calculate_account_len::<Account>(&extensions).unwrap_or(Account::LEN)
+}
+
+fn build_plan(
+    client: &RpcClient,
+    sender: &Pubkey,
+    mint: Option<Pubkey>,
+    csv: &str,
+) -> Result<AirdropPlan, SolanaError> {
+    let mut mints = HashMap::new();
+    let decimals = match mint {
+        Some(mint_pubkey) => {
+            let mint_state = fetch_mint(client, &mint_pubkey)?;
+            let decimals = mint_state.base.decimals;
+            mints.insert(mint_pubkey, mint_state);
+            decimals
+        }
+        None => SOL_DECIMALS,
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+
+    let mut total_rows = 0;
+    let mut invalid_rows = Vec::new();
+    let mut candidates: Vec<(u64, String, Pubkey, u64)> = Vec::new();
+    let mut seen = HashSet::new();
+
+    for record in reader.records() {
+        let record = record.map_err(|e| SolanaError::InvalidInput(format!("Invalid CSV: {e}")))?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+
+        let address = record.get(0).unwrap_or_default().to_string();
+        let amount = record.get(1).unwrap_or_default().to_string();
+
+        // Allow an optional header row
+        if line == 1 && address.eq_ignore_ascii_case("address") {
+            continue;
+        }
+
+        if address.is_empty() && amount.is_empty() {
+            continue;
+        }
+
+        total_rows += 1;
+
+        let mut reject = |error: String| {
+            invalid_rows.push(RowError {
+                line,
+                address: address.clone(),
+                amount: amount.clone(),
+                error,
+            })
+        };
+
+        if record.len() != 2 {
+            reject("Expected exactly two columns: address,amount".to_string());
+            continue;
+        }
+
+        let Ok(recipient) = address.parse::<Pubkey>() else {
+            reject("Invalid recipient address".to_string());
+            continue;
+        };
+
+        let raw_amount = match parse_ui_amount(&amount, decimals) {
+            Ok(0) => {
+                reject("Amount must be greater than zero".to_string());
+                continue;
+            }
+            Ok(raw_amount) => raw_amount,
+            Err(e) => {
+                reject(e.to_string());
+                continue;
+            }
+        };
+
+        if recipient == *sender {
+            reject("Sender and recipient cannot be the same".to_string());
+            continue;
+        }
+
+        if !seen.insert(recipient) {
+            reject("Duplicate recipient".to_string());
+            continue;
+        }
+
+        let transfer = Transfer {
+            recipient,
+            mint,
+            amount: raw_amount,
+        };
+        if let Err(violation) = policy().check_transfer("/airdrop/execute", &transfer) {
+            reject(format!(
+                "Policy violation ({}): {}",
+                violation.rule, violation.reason
+            ));
+            continue;
+        }
+
+        candidates.push((line, address.clone(), recipient, raw_amount));
+    }
+
+    // Look up which destination accounts already exist
+    let program_id = mint.map(|mint_pubkey| mints[&mint_pubkey].program_id);
+    let targets: Vec<Pubkey> = candidates
+        .iter()
+        .map(|(_, _, recipient, _)| match (mint, program_id) {
+            (Some(mint_pubkey), Some(program_id)) => {
+                get_associated_token_address_with_program_id(recipient, &mint_pubkey, &program_id)
+            }
+            _ => *recipient,
+        })
+        .collect();
+
+    let mut exists = Vec::with_capacity(targets.len());
+    for chunk in targets.chunks(100) {
+        exists.extend(
+            client
+                .get_multiple_accounts(chunk)?
+                .into_iter()
+                .map(|account| account.is_some()),
+        );
+    }
+
+    let new_account_minimum = client.get_minimum_balance_for_rent_exemption(0)?;
+
+    let mut payouts = Vec::new();
+    let mut ata_creations = 0;
+
+    for ((line, address, recipient, raw_amount), exists) in candidates.into_iter().zip(exists) {
+        // A SOL transfer cannot create an account below the rent-exempt minimum
+        if mint.is_none() && !exists && raw_amount < new_account_minimum {
+            invalid_rows.push(RowError {
+                line,
+                address,
+                amount: format_ui_amount(raw_amount, decimals),
+                error: format!(
+                    "Amount is below the rent-exempt minimum ({new_account_minimum} lamports) for a new account"
+                ),
+            });
+            continue;
+        }
+
+        if mint.is_some() && !exists {
+            ata_creations += 1;
+        }
+
+        payouts.push(Payout {
+            recipient,
+            amount: raw_amount,
+            mint,
+        });
+    }
+
+    invalid_rows.sort_by_key(|row| row.line);
+
+    let batches = plan_batch(&payouts, sender, sender, &mints, &[])?;
+
+    let ata_rent_lamports = match (mint, program_id) {
+        (Some(mint_pubkey), Some(program_id)) if ata_creations > 0 => {
+            let len = token_account_len(client, &mint_pubkey, &program_id);
+            client.get_minimum_balance_for_rent_exemption(len)? * ata_creations as u64
+        }
+        _ => 0,
+    };
+
+    let fee_lamports = batches.len() as u64 * LAMPORTS_PER_SIGNATURE;
+    let total_amount = payouts
+        .iter()
+        .fold(0u64, |total, payout| total.saturating_add(payout.amount));
+
+    let sender_lamports = client.get_balance(sender)?;
+
+    let (required_lamports, sender_token_amount) = match (mint, program_id) {
+        (Some(mint_pubkey), Some(program_id)) => {
+            let sender_ata =
+                get_associated_token_address_with_program_id(sender, &mint_pubkey, &program_id);
+            let sender_token_amount = client
+                .get_token_account_balance(&sender_ata)
+                .ok()
+                .and_then(|balance| balance.amount.parse::<u64>().ok())
+                .unwrap_or(0);
+            (ata_rent_lamports + fee_lamports, Some(sender_token_amount))
+        }
+        _ => (total_amount.saturating_add(fee_lamports), None),
+    };
+
+    let sufficient_funds = sender_lamports >= required_lamports
+        && sender_token_amount.is_none_or(|amount| amount >= total_amount);
+
+    let airdrop_id = hashv(&[
+        sender.as_ref(),
+        mint.as_ref().map(|m| m.as_ref()).unwrap_or_default(),
+        csv.as_bytes(),
+    ])
+    .to_string();
+
+    let report = AirdropPlanResponse {
+        airdrop_id,
+        sender: sender.to_string(),
+        mint: mint.map(|m| m.to_string()),
+        decimals,
+        total_rows,
+        valid_rows: payouts.len(),
+        invalid_rows,
+        total_amount,
+        total_ui_amount: format_ui_amount(total_amount, decimals),
+        ata_creations,
+        ata_rent_lamports,
+        transaction_count: batches.len(),
+        fee_lamports,
+        required_lamports,
+        sender_lamports,
+        sender_token_amount,
+        sufficient_funds,
+    };
+
+    let transfers = payouts
+        .iter()
+        .map(|payout| Transfer {
+            recipient: payout.recipient,
+            mint: payout.mint,
+            amount: payout.amount,
+        })
+        .collect();
+
+    Ok(AirdropPlan {
+        mint,
+        transfers,
+        batches,
+        report,
+    })
+}
+
+fn parse_mint(mint: Option<&String>) -> Result<Option<Pubkey>, SolanaError> {
+    mint.filter(|s| !s.trim().is_empty())
+        .map(|mint| {
+            mint.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))
+        })
+        .transpose()
+}
+
+async fn plan_airdrop(
+    Json(payload): Json<AirdropPlanRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /airdrop/plan - sender: {:?}, mint: {:?}",
+        payload.sender, payload.mint
+    );
+
+    // Validate required fields are present and not empty
+    let sender = payload
+        .sender
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let csv = payload
+        .csv
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let sender_pubkey = sender
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid sender address".to_string()))?;
+
+    let mint_pubkey = parse_mint(payload.mint.as_ref())?;
+
+    let client = get_rpc_client();
+    let plan = build_plan(&client, &sender_pubkey, mint_pubkey, csv)?;
+
+    info!(
+        "Airdrop {} planned: {} valid rows, {} invalid, {} transactions",
+        plan.report.airdrop_id,
+        plan.report.valid_rows,
+        plan.report.invalid_rows.len(),
+        plan.report.transaction_count
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": plan.report
+    });
+
+    info!("Response: 200 - Airdrop dry-run report generated successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn execute_airdrop(
+    State(state): State<AirdropState>,
+    headers: HeaderMap,
+    Json(payload): Json<AirdropExecuteRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("POST /airdrop/execute - mint: {:?}", payload.mint);
+
+    // Validate required fields are present and not empty
+    let secret = payload
+        .secret
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let csv = payload
+        .csv
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let keypair = parse_keypair(secret)?;
+    let mint_pubkey = parse_mint(payload.mint.as_ref())?;
+
+    let client = get_rpc_client();
+    let plan = build_plan(&client, &keypair.pubkey(), mint_pubkey, csv)?;
+    let airdrop_id = plan.report.airdrop_id.clone();
+
+    if !plan.report.invalid_rows.is_empty() {
+        return Err(SolanaError::InvalidInput(format!(
+            "CSV contains {} invalid rows - run /airdrop/plan for details",
+            plan.report.invalid_rows.len()
+        )));
+    }
+
+    let journal = Journal::open(&airdrop_id);
+
+    // Balances and velocity only apply to the first run; a resumed run has already paid some rows
+    if !journal.exists() {
+        if !plan.report.sufficient_funds {
+            return Err(SolanaError::InvalidInput(
+                "Sender has insufficient funds for this airdrop".to_string(),
+            ));
+        }
+
+        policy().consume_velocity(api_key(&headers), &plan.transfers)?;
+    }
+
+    let progress = {
+        let mut progress = state.progress.lock().unwrap();
+
+        if let Some(existing) = progress
+            .get(&airdrop_id)
+            .filter(|p| p.status == AirdropStatus::Running)
+        {
+            info!("Airdrop {} is already running", airdrop_id);
+
+            return Ok(Json(serde_json::json!({
+                "success": true,
+                "data": existing
+            })));
+        }
+
+        let started = AirdropProgress {
+            airdrop_id: airdrop_id.clone(),
+            status: AirdropStatus::Running,
+            total_batches: plan.batches.len(),
+            confirmed_batches: 0,
+            total_recipients: plan.report.valid_rows,
+            paid_recipients: 0,
+            signatures: Vec::new(),
+            error: None,
+        };
+        progress.insert(airdrop_id.clone(), started.clone());
+        started
+    };
+
+    info!(
+        "Starting airdrop {} with {} transactions (mint: {:?})",
+        airdrop_id,
+        plan.batches.len(),
+        plan.mint
+    );
+
+    let task_state = state.clone();
+    tokio::task::spawn_blocking(move || {
+        let client = get_rpc_client();
+        let result = run_airdrop(&client, &keypair, &plan, &journal, &task_state, &airdrop_id);
+
+        let mut progress = task_state.progress.lock().unwrap();
+        if let Some(entry) = progress.get_mut(&airdrop_id) {
+            match result {
+                Ok(()) => entry.status = AirdropStatus::Completed,
+                Err(e) => {
+                    warn!("Airdrop {} failed: {}", airdrop_id, e);
+                    entry.status = AirdropStatus::Failed;
+                    entry.error = Some(e.to_string());
+                }
+            }
+        }
+    });
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": progress
+    });
+
+    info!("Response: 200 - Airdrop execution started");
+
+    Ok(Json(json_response))
+}
+
+async fn get_airdrop_progress(
+    State(state): State<AirdropState>,
+    Path(airdrop_id): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /airdrop/{}", airdrop_id);
+
+    let in_memory = state.progress.lock().unwrap().get(&airdrop_id).cloned();
+
+    // Fall back to the journal for airdrops started before a restart
+    let progress = match in_memory {
+        Some(progress) => progress,
+        None => {
+            if airdrop_id.parse::<solana_sdk::hash::Hash>().is_err() {
+                return Err(SolanaError::InvalidInput("Invalid airdrop id".to_string()));
+            }
+
+            let journal = Journal::open(&airdrop_id);
+            if !journal.exists() {
+                return Err(SolanaError::InvalidInput("Airdrop not found".to_string()));
+            }
+
+            progress_from_journal(&airdrop_id, &journal.load()?)
+        }
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": progress
+    });
+
+    info!("Response: 200 - Airdrop progress fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+fn replay(entries: &[JournalEntry], batch_count: usize) -> Vec<BatchState> {
+    let mut states = vec![BatchState::Pending; batch_count];
+
+    for entry in entries {
+        match entry {
+            JournalEntry::Planned { .. } => {}
+            JournalEntry::Sent {
+                batch,
+                signature,
+                last_valid_block_height,
+            } => {
+                if let (Some(state), Ok(signature)) =
+                    (states.get_mut(*batch), Signature::from_str(signature))
+                {
+                    *state = BatchState::Sent {
+                        signature,
+                        last_valid_block_height: *last_valid_block_height,
+                    };
+                }
+            }
+            JournalEntry::Confirmed { batch, signature } => {
+                if let (Some(state), Ok(signature)) =
+                    (states.get_mut(*batch), Signature::from_str(signature))
+                {
+                    *state = BatchState::Confirmed(signature);
+                }
+            }
+            JournalEntry::Failed { batch, .. } => {
+                if let Some(state) = states.get_mut(*batch) {
+                    *state = BatchState::Pending;
+                }
+            }
+        }
+    }
+
+    states
+}
+
+fn progress_from_journal(airdrop_id: &str, entries: &[JournalEntry]) -> AirdropProgress {
+    let batches = entries
+        .iter()
+        .find_map(|entry| match entry {
+            JournalEntry::Planned { batches } => Some(batches.clone()),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let states = replay(entries, batches.len());
+
+    let mut progress = AirdropProgress {
+        airdrop_id: airdrop_id.to_string(),
+        status: AirdropStatus::Interrupted,
+        total_batches: batches.len(),
+        confirmed_batches: 0,
+        total_recipients: batches.iter().map(Vec::len).sum(),
+        paid_recipients: 0,
+        signatures: Vec::new(),
+        error: None,
+    };
+
+    for (state, recipients) in states.iter().zip(&batches) {
+        if let BatchState::Confirmed(signature) = state {
+            progress.confirmed_batches += 1;
+            progress.paid_recipients += recipients.len();
+            progress.signatures.push(signature.to_string());
+        }
+    }
+
+    if progress.confirmed_batches == progress.total_batches {
+        progress.status = AirdropStatus::Completed;
+    }
+
+    progress
+}
+
+// Waits until the transaction lands or its blockhash can no longer be used
+fn await_outcome(
+    client: &RpcClient,
+    signature: &Signature,
+    last_valid_block_height: u64,
+) -> Result<Outcome, SolanaError> {
+    loop {
+        // Search history too, so a resumed run finds transactions that landed long ago
+        let status = client.get_signature_status_with_commitment_and_history(
+            signature,
+            CommitmentConfig::confirmed(),
+            true,
+        )?;
+
+        match status {
+            Some(Ok(())) => return Ok(Outcome::Landed),
+            Some(Err(e)) => return Ok(Outcome::Failed(e.to_string())),
+            None => {}
+        }
+
+        if client.get_block_height()? > last_valid_block_height {
+            // Re-check once the blockhash has expired to close the race with a late landing
+            return Ok(
+                match client.get_signature_status_with_commitment_and_history(
+                    signature,
+                    CommitmentConfig::confirmed(),
+                    true,
+                )? {
+                    Some(Ok(())) => Outcome::Landed,
+                    Some(Err(e)) => Outcome::Failed(e.to_string()),
+                    None => Outcome::Expired,
+                },
+            );
+        }
+
+        thread::sleep(CONFIRMATION_POLL_INTERVAL);
+    }
+}
+
+fn run_airdrop(
+    client: &RpcClient,
+    keypair: &Keypair,
+    plan: &AirdropPlan,
+    journal: &Journal,
+    state: &AirdropState,
+    airdrop_id: &str,
+) -> Result<(), SolanaError> {
+    let sender = keypair.pubkey();
+    let planned_batches: Vec<Vec<usize>> = plan
+        .batches
+        .iter()
+        .map(|batch| batch.recipients.clone())
+        .collect();
+
+    let entries = journal.load()?;
+
+    // A resumed run must pack recipients exactly as the journaled run did
+    match entries.iter().find_map(|entry| match entry {
+        JournalEntry::Planned { batches } => Some(batches),
+        _ => None,
+    }) {
+        Some(batches) if *batches != planned_batches => {
+            return Err(SolanaError::InvalidInput(
+                "Journaled plan does not match the current plan".to_string(),
+            ));
+        }
+        Some(_) => info!("Resuming airdrop {} from journal", airdrop_id),
+        None => journal.append(&JournalEntry::Planned {
+            batches: planned_batches,
+        })?,
+    }
+
+    let mut states = replay(&entries, plan.batches.len());
+
+    for (index, batch) in plan.batches.iter().enumerate() {
+        let mut attempts = 0;
+
+        loop {
+            match states[index] {
+                BatchState::Confirmed(signature) => {
+                    let mut progress = state.progress.lock().unwrap();
+                    if let Some(progress) = progress.get_mut(airdrop_id) {
+                        progress.confirmed_batches += 1;
+                        progress.paid_recipients += batch.recipients.len();
+                        progress.signatures.push(signature.to_string());
+                    }
+                    break;
+                }
+                BatchState::Sent {
+                    signature,
+                    last_valid_block_height,
+                } => match await_outcome(client, &signature, last_valid_block_height)? {
+                    Outcome::Landed => {
+                        journal.append(&JournalEntry::Confirmed {
+                            batch: index,
+                            signature: signature.to_string(),
+                        })?;
+                        info!(
+                            "Airdrop {} batch {} confirmed: {}",
+                            airdrop_id, index, signature
+                        );
+                        states[index] = BatchState::Confirmed(signature);
+                    }
+                    Outcome::Failed(error) => {
+                        journal.append(&JournalEntry::Failed {
+                            batch: index,
+                            signature: signature.to_string(),
+                            error: error.clone(),
+                        })?;
+                        warn!("Airdrop {} batch {} failed: {}", airdrop_id, index, error);
+                        states[index] = BatchState::Pending;
+                    }
+                    Outcome::Expired => {
+                        warn!(
+                            "Airdrop {} batch {} expired: {}",
+                            airdrop_id, index, signature
+                        );
+                        states[index] = BatchState::Pending;
+                    }
+                },
+                BatchState::Pending => {
+                    if attempts == MAX_SEND_ATTEMPTS {
+                        return Err(SolanaError::TokenError(format!(
+                            "Batch {index} did not land after {MAX_SEND_ATTEMPTS} attempts"
+                        )));
+                    }
+                    attempts += 1;
+
+                    let (blockhash, last_valid_block_height) = client
+                        .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())?;
+
+                    let transaction = Transaction::new_signed_with_payer(
+                        &batch.instructions,
+                        Some(&sender),
+                        &[keypair],
+                        blockhash,
+                    );
+                    let signature = transaction.signatures[0];
+
+                    // Write-ahead: record the signature before it can possibly land
+                    journal.append(&JournalEntry::Sent {
+                        batch: index,
+                        signature: signature.to_string(),
+                        last_valid_block_height,
+                    })?;
+
+                    if let Err(e) = client.send_transaction(&transaction) {
+                        // A rejected preflight means the transaction was never forwarded
+                        if let ClientErrorKind::RpcError(RpcError::RpcResponseError { .. }) =
+                            e.kind()
+                        {
+                            journal.append(&JournalEntry::Failed {
+                                batch: index,
+                                signature: signature.to_string(),
+                                error: e.to_string(),
+                            })?;
+                            return Err(e.into());
+                        }
+
+                        warn!(
+                            "Airdrop {} batch {} send error, awaiting outcome: {}",
+                            airdrop_id, index, e
+                        );
+                    }
+
+                    states[index] = BatchState::Sent {
+                        signature,
+                        last_valid_block_height,
+                    };
+                }
+            }
+        }
+    }
+
+    info!("Airdrop {} completed", airdrop_id);
+
+    Ok(())
+}
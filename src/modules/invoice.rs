@@ -0,0 +1,359 @@
+use crate::modules::pay::{
+    check_payment, encode_transfer_request, normalize_amount, ExpectedPayment, PaymentCheck,
+    TransferRequest,
+};
+use crate::modules::token::fetch_mint;
+use crate::utils::amount::{format_ui_amount, SOL_DECIMALS};
+use crate::utils::errors::SolanaError;
+use crate::utils::pubkey::{parse_optional_pubkey, parse_pubkey};
+use crate::utils::solana_client::get_rpc_client;
+use crate::utils::storage::Collection;
+use axum::{
+    extract::Path,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use std::env;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const INVOICES: &str = "invoices";
+// Ids of invoices the poller still checks, so settled invoices are never loaded again
+const OPEN_INVOICES: &str = "open_invoices";
+const DEFAULT_EXPIRES_IN_SECS: u64 = 3_600;
+const MAX_EXPIRES_IN_SECS: u64 = 30 * 24 * 3_600;
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 10;
+const DEFAULT_LATE_PAYMENT_SECS: u64 = 24 * 3_600;
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateInvoiceRequest {
+    pub recipient: Option<String>,
+    // Decimal amount in SOL or token units
+    pub amount: Option<String>,
+    // Omitted for SOL invoices
+    pub mint: Option<String>,
+    #[serde(rename = "expiresIn")]
+    pub expires_in: Option<u64>, // Seconds
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+    // Arbitrary caller data stored with the invoice, e.g. an order id
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Pending,
+    Paid,
+    // Less than the amount arrived; stays open for top-ups until the late-payment window closes
+    Underpaid,
+    Overpaid,
+    // Nothing arrived by expiry; late payments still move it to another status
+    Expired,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: String,
+    pub status: InvoiceStatus,
+    pub recipient: String,
+    pub mint: Option<String>,
+    pub decimals: u8,
+    pub amount: u64,
+    pub ui_amount: String,
+    pub reference: String,
+    pub pay_url: String,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub received_amount: u64,
+    pub received_ui_amount: String,
+    // Valid payments seen so far, oldest first
+    pub signatures: Vec<String>,
+    // Every signature already examined, so each transaction is fetched once
+    pub checked_signatures: Vec<String>,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub settled_at: Option<i64>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/invoices", post(create_invoice))
+        .route("/invoices/:id", get(get_invoice))
+}
+
+// Polls open invoices every INVOICE_POLL_INTERVAL_SECS until they are paid, or until
+// INVOICE_LATE_PAYMENT_SECS after expiry so late payments are still recorded
+pub fn spawn_invoice_poller() {
+    let interval = env::var("INVOICE_POLL_INTERVAL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .unwrap_or(DEFAULT_POLL_INTERVAL_SECS);
+    let late_payment_secs = env::var("INVOICE_LATE_PAYMENT_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .unwrap_or(DEFAULT_LATE_PAYMENT_SECS);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+        loop {
+            ticker.tick().await;
+
+            let result =
+                tokio::task::spawn_blocking(move || poll_invoices(late_payment_secs)).await;
+            match result {
+                Ok(Err(e)) => warn!("Invoice poll failed: {}", e),
+                Err(e) => warn!("Invoice poll task panicked: {}", e),
+                Ok(Ok(())) => {}
+            }
+        }
+    });
+}
+
+fn poll_invoices(late_payment_secs: u64) -> Result<(), SolanaError> {
+    let invoices = Collection::open(INVOICES)?;
+    let open_invoices = Collection::open(OPEN_INVOICES)?;
+    let client = get_rpc_client();
+
+    for invoice_id in open_invoices.values::<String>()? {
+        let Some(mut invoice) = invoices.get::<Invoice>(&invoice_id)? else {
+            open_invoices.remove(&invoice_id)?;
+            continue;
+        };
+
+        let previous_status = invoice.status;
+        let checked = invoice.checked_signatures.len();
+
+        // One failing invoice should not hold up the others
+        if let Err(e) = update_invoice(&client, &mut invoice, late_payment_secs) {
+            warn!("Failed to check invoice {}: {}", invoice.id, e);
+            continue;
+        }
+
+        if invoice.status != previous_status
+            || invoice.settled_at.is_some()
+            || invoice.checked_signatures.len() != checked
+        {
+            invoices.insert(&invoice.id, &invoice)?;
+        }
+
+        if invoice.settled_at.is_some() {
+            open_invoices.remove(&invoice.id)?;
+        }
+
+        if invoice.status != previous_status {
+            info!(
+                "Invoice {} moved from {:?} to {:?}",
+                invoice.id, previous_status, invoice.status
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn update_invoice(
+    client: &RpcClient,
+    invoice: &mut Invoice,
+    late_payment_secs: u64,
+) -> Result<(), SolanaError> {
+    let reference = parse_pubkey(Some(&invoice.reference), "reference")?;
+
+    let expected = ExpectedPayment {
+        recipient: parse_pubkey(Some(&invoice.recipient), "recipient")?,
+        mint: parse_optional_pubkey(invoice.mint.as_ref(), "mint")?,
+        // Every payment counts towards the total, so accept any amount here
+        amount: 0,
+        memo: invoice.memo.clone(),
+    };
+
+    let signatures = client.get_signatures_for_address_with_config(
+        &reference,
+        GetConfirmedSignaturesForAddress2Config {
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        },
+    )?;
+
+    // Oldest first
+    for signature_info in signatures.iter().rev() {
+        if invoice
+            .checked_signatures
+            .contains(&signature_info.signature)
+        {
+            continue;
+        }
+
+        if signature_info.err.is_none() {
+            let signature = signature_info
+                .signature
+                .parse::<Signature>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid signature from RPC".to_string()))?;
+
+            if let PaymentCheck::Valid { received, .. } =
+                check_payment(client, &signature, &expected)?
+            {
+                if received > 0 {
+                    invoice.received_amount = invoice.received_amount.saturating_add(received);
+                    invoice.signatures.push(signature_info.signature.clone());
+                }
+            }
+        }
+
+        invoice
+            .checked_signatures
+            .push(signature_info.signature.clone());
+    }
+
+    invoice.received_ui_amount = format_ui_amount(invoice.received_amount, invoice.decimals);
+
+    let now = Utc::now().timestamp();
+    let expired = now >= invoice.expires_at;
+
+    invoice.status = match invoice.received_amount {
+        0 if expired => InvoiceStatus::Expired,
+        0 => InvoiceStatus::Pending,
+        received if received < invoice.amount => InvoiceStatus::Underpaid,
+        received if received == invoice.amount => InvoiceStatus::Paid,
+        _ => InvoiceStatus::Overpaid,
+    };
+
+    // Unpaid and underpaid invoices are still checked for a while after they expire
+    let open = matches!(
+        invoice.status,
+        InvoiceStatus::Pending | InvoiceStatus::Underpaid | InvoiceStatus::Expired
+    );
+    let late_payments_closed = now >= invoice.expires_at.saturating_add(late_payment_secs as i64);
+    if !open || late_payments_closed {
+        invoice.settled_at = Some(now);
+    }
+
+    Ok(())
+}
+
+async fn create_invoice(
+    Json(payload): Json<CreateInvoiceRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /invoices - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let amount = payload
+        .amount
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let expires_in = payload.expires_in.unwrap_or(DEFAULT_EXPIRES_IN_SECS);
+    if expires_in == 0 || expires_in > MAX_EXPIRES_IN_SECS {
+        return Err(SolanaError::InvalidInput(format!(
+            "expiresIn must be between 1 and {MAX_EXPIRES_IN_SECS} seconds"
+        )));
+    }
+
+    // Parse public keys AFTER validation
+    let recipient_pubkey = parse_pubkey(payload.recipient.as_ref(), "recipient")?;
+    let mint_pubkey = parse_optional_pubkey(payload.mint.as_ref(), "mint")?;
+
+    let decimals = match &mint_pubkey {
+        Some(mint_pubkey) => fetch_mint(&get_rpc_client(), mint_pubkey)?.base.decimals,
+        None => SOL_DECIMALS,
+    };
+
+    let (ui_amount, raw_amount) = normalize_amount(amount, decimals)?;
+    if raw_amount == 0 {
+        return Err(SolanaError::InvalidInput(
+            "Amount must be greater than zero".to_string(),
+        ));
+    }
+
+    let memo = payload.memo.clone().filter(|memo| !memo.is_empty());
+
+    // A fresh reference key identifies the payment on-chain
+    let reference: Pubkey = Keypair::new().pubkey();
+
+    let pay_url = encode_transfer_request(&TransferRequest {
+        recipient: recipient_pubkey,
+        amount: Some(ui_amount.clone()),
+        spl_token: mint_pubkey,
+        references: vec![reference],
+        label: payload.label.clone(),
+        message: payload.message.clone(),
+        memo: memo.clone(),
+    });
+
+    let now = Utc::now().timestamp();
+
+    let invoice = Invoice {
+        id: Uuid::new_v4().to_string(),
+        status: InvoiceStatus::Pending,
+        recipient: recipient_pubkey.to_string(),
+        mint: mint_pubkey.map(|mint| mint.to_string()),
+        decimals,
+        amount: raw_amount,
+        ui_amount,
+        reference: reference.to_string(),
+        pay_url,
+        label: payload.label,
+        message: payload.message,
+        memo,
+        metadata: payload.metadata,
+        received_amount: 0,
+        received_ui_amount: format_ui_amount(0, decimals),
+        signatures: Vec::new(),
+        checked_signatures: Vec::new(),
+        created_at: now,
+        expires_at: now + expires_in as i64,
+        settled_at: None,
+    };
+
+    Collection::open(INVOICES)?.insert(&invoice.id, &invoice)?;
+    Collection::open(OPEN_INVOICES)?.insert(&invoice.id, &invoice.id)?;
+
+    info!(
+        "Created invoice {} for {} to {} (reference {})",
+        invoice.id, invoice.ui_amount, invoice.recipient, invoice.reference
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": invoice
+    });
+
+    info!("Response: 200 - Invoice created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_invoice(
+    Path(invoice_id): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /invoices/{}", invoice_id);
+
+    let invoice: Invoice = Collection::open(INVOICES)?
+        .get(&invoice_id)?
+        .ok_or_else(|| SolanaError::InvalidInput("Invoice not found".to_string()))?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": invoice
+    });
+
+    info!("Response: 200 - Invoice fetched successfully");
+
+    Ok(Json(json_response))
+}
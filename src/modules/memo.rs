@@ -0,0 +1,84 @@
+use crate::modules::token::{instruction_response, InstructionResponse};
+use crate::utils::errors::SolanaError;
+use axum::{routing::post, Json, Router};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use tracing::info;
+
+#[derive(Deserialize, Serialize)]
+pub struct MemoRequest {
+    pub memo: Option<String>,
+    pub signers: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+pub struct MemoResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub memo: String,
+}
+
+pub fn routes() -> Router {
+    Router::new().route("/memo", post(create_memo))
+}
+
+// Builds an SPL Memo instruction; every listed signer must sign the transaction
+pub fn build_memo_instruction(
+    memo: &str,
+    signers: Option<&Vec<String>>,
+) -> Result<Instruction, SolanaError> {
+    if memo.trim().is_empty() {
+        return Err(SolanaError::InvalidInput(
+            "Memo cannot be empty".to_string(),
+        ));
+    }
+
+    let signer_pubkeys = signers
+        .map(|signers| {
+            signers
+                .iter()
+                .map(|s| {
+                    s.parse::<Pubkey>()
+                        .map_err(|_| SolanaError::InvalidInput(format!("Invalid memo signer: {s}")))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+
+    Ok(spl_memo::build_memo(memo.as_bytes(), &signer_refs))
+}
+
+async fn create_memo(
+    Json(payload): Json<MemoRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /memo - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let memo = payload
+        .memo
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let instruction = build_memo_instruction(memo, payload.signers.as_ref())?;
+
+    let response = MemoResponse {
+        instruction: instruction_response(&instruction),
+        memo: memo.to_string(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Memo instruction created successfully");
+
+    Ok(Json(json_response))
+}
@@ -1,4 +1,16 @@
+pub mod account;
+pub mod airdrop;
+pub mod invoice;
 pub mod keypair;
+pub mod memo;
 pub mod message;
+pub mod nonce;
+pub mod pay;
+pub mod pay_request;
 pub mod send;
+pub mod stake;
+pub mod stream;
 pub mod token;
+pub mod validators;
+pub mod wallet;
+pub mod webhook;
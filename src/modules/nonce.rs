@@ -0,0 +1,373 @@
+use crate::modules::keypair::KeypairResponse;
+use crate::modules::token::{instruction_response, InstructionResponse};
+use crate::utils::errors::SolanaError;
+use crate::utils::pubkey::{parse_optional_pubkey, parse_pubkey};
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::Path,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_client::nonce_utils;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    nonce::{state::Data, State},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+use tracing::info;
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateNonceRequest {
+    pub payer: Option<String>,
+    // Generated server-side when omitted
+    #[serde(rename = "nonceAccount")]
+    pub nonce_account: Option<String>,
+    // Defaults to the payer
+    pub authority: Option<String>,
+    // Defaults to the rent-exempt minimum
+    pub lamports: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AdvanceNonceRequest {
+    #[serde(rename = "nonceAccount")]
+    pub nonce_account: Option<String>,
+    pub authority: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AuthorizeNonceRequest {
+    #[serde(rename = "nonceAccount")]
+    pub nonce_account: Option<String>,
+    pub authority: Option<String>,
+    #[serde(rename = "newAuthority")]
+    pub new_authority: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct WithdrawNonceRequest {
+    #[serde(rename = "nonceAccount")]
+    pub nonce_account: Option<String>,
+    pub authority: Option<String>,
+    pub destination: Option<String>,
+    pub lamports: Option<u64>,
+}
+
+// Opt-in for transaction builders: use a durable nonce instead of a recent blockhash
+#[derive(Deserialize, Serialize)]
+pub struct UseNonceRequest {
+    #[serde(rename = "nonceAccount")]
+    pub nonce_account: Option<String>,
+    // Defaults to the transaction's fee payer
+    #[serde(rename = "nonceAuthority")]
+    pub nonce_authority: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CreateNonceResponse {
+    pub nonce_account: String,
+    pub authority: String,
+    pub lamports: u64,
+    pub space: usize,
+    pub instructions: Vec<InstructionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce_keypair: Option<KeypairResponse>,
+}
+
+#[derive(Serialize)]
+pub struct NonceInstructionResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub nonce_account: String,
+    pub authority: String,
+}
+
+#[derive(Serialize)]
+pub struct NonceAccountResponse {
+    pub address: String,
+    pub lamports: u64,
+    pub authority: String,
+    pub nonce: String,
+    pub lamports_per_signature: u64,
+}
+
+// The nonce value and advance instruction to use in place of a recent blockhash
+pub struct DurableNonce {
+    pub nonce_account: Pubkey,
+    pub nonce: Hash,
+    pub advance_instruction: Instruction,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/nonce/create", post(create_nonce))
+        .route("/nonce/advance", post(advance_nonce))
+        .route("/nonce/authorize", post(authorize_nonce))
+        .route("/nonce/withdraw", post(withdraw_nonce))
+        .route("/nonce/:address", get(get_nonce))
+}
+
+// Fetches an initialized nonce account owned by the system program
+pub fn fetch_nonce(client: &RpcClient, nonce_account: &Pubkey) -> Result<(u64, Data), SolanaError> {
+    let account = client
+        .get_account_with_commitment(nonce_account, client.commitment())?
+        .value
+        .ok_or_else(|| SolanaError::InvalidInput("Nonce account does not exist".to_string()))?;
+
+    let data = nonce_utils::data_from_account(&account)
+        .map_err(|e| SolanaError::InvalidInput(format!("Invalid nonce account: {e}")))?;
+
+    Ok((account.lamports, data))
+}
+
+// Loads the stored nonce and checks the caller's authority against it
+pub fn resolve_durable_nonce(
+    client: &RpcClient,
+    use_nonce: &UseNonceRequest,
+    default_authority: &Pubkey,
+) -> Result<DurableNonce, SolanaError> {
+    let nonce_account = parse_pubkey(use_nonce.nonce_account.as_ref(), "nonce account")?;
+
+    let authority = parse_optional_pubkey(use_nonce.nonce_authority.as_ref(), "nonce authority")?
+        .unwrap_or(*default_authority);
+
+    let (_, data) = fetch_nonce(client, &nonce_account)?;
+
+    if data.authority != authority {
+        return Err(SolanaError::AuthorityMismatch(format!(
+            "Nonce authority is {}, not {}",
+            data.authority, authority
+        )));
+    }
+
+    Ok(DurableNonce {
+        nonce_account,
+        nonce: data.blockhash(),
+        advance_instruction: system_instruction::advance_nonce_account(&nonce_account, &authority),
+    })
+}
+
+async fn create_nonce(
+    Json(payload): Json<CreateNonceRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /nonce/create - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let payer_pubkey = parse_pubkey(payload.payer.as_ref(), "payer")?;
+
+    let authority_pubkey =
+        parse_optional_pubkey(payload.authority.as_ref(), "authority")?.unwrap_or(payer_pubkey);
+
+    // Generate the nonce keypair server-side when none was provided
+    let (nonce_pubkey, nonce_keypair) = match payload
+        .nonce_account
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+    {
+        Some(nonce_account) => (
+            nonce_account.parse::<Pubkey>().map_err(|_| {
+                SolanaError::InvalidInput("Invalid nonce account address".to_string())
+            })?,
+            None,
+        ),
+        None => {
+            let keypair = Keypair::new();
+            (keypair.pubkey(), Some(keypair))
+        }
+    };
+
+    if nonce_pubkey == payer_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Payer and nonce account cannot be the same".to_string(),
+        ));
+    }
+
+    let space = State::size();
+    let client = get_rpc_client();
+    let rent_lamports = client.get_minimum_balance_for_rent_exemption(space)?;
+
+    let lamports = payload.lamports.unwrap_or(rent_lamports);
+    if lamports < rent_lamports {
+        return Err(SolanaError::InvalidInput(format!(
+            "Nonce account needs at least {rent_lamports} lamports to be rent-exempt"
+        )));
+    }
+
+    info!(
+        "Creating nonce account {} funded by {} with {} lamports, authority: {}",
+        nonce_pubkey, payer_pubkey, lamports, authority_pubkey
+    );
+
+    // create_account followed by initialize_nonce_account
+    let instructions = system_instruction::create_nonce_account(
+        &payer_pubkey,
+        &nonce_pubkey,
+        &authority_pubkey,
+        lamports,
+    );
+
+    let response = CreateNonceResponse {
+        nonce_account: nonce_pubkey.to_string(),
+        authority: authority_pubkey.to_string(),
+        lamports,
+        space,
+        instructions: instructions.iter().map(instruction_response).collect(),
+        nonce_keypair: nonce_keypair.map(|keypair| KeypairResponse {
+            pubkey: keypair.pubkey().to_string(),
+            secret: bs58::encode(&keypair.to_bytes()).into_string(),
+        }),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Nonce account creation instructions generated successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn advance_nonce(
+    Json(payload): Json<AdvanceNonceRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /nonce/advance - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let nonce_pubkey = parse_pubkey(payload.nonce_account.as_ref(), "nonce account")?;
+    let authority_pubkey = parse_pubkey(payload.authority.as_ref(), "authority")?;
+
+    let instruction = system_instruction::advance_nonce_account(&nonce_pubkey, &authority_pubkey);
+
+    let response = NonceInstructionResponse {
+        instruction: instruction_response(&instruction),
+        nonce_account: nonce_pubkey.to_string(),
+        authority: authority_pubkey.to_string(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Nonce advance instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn authorize_nonce(
+    Json(payload): Json<AuthorizeNonceRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /nonce/authorize - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let nonce_pubkey = parse_pubkey(payload.nonce_account.as_ref(), "nonce account")?;
+    let authority_pubkey = parse_pubkey(payload.authority.as_ref(), "authority")?;
+    let new_authority_pubkey = parse_pubkey(payload.new_authority.as_ref(), "new authority")?;
+
+    if authority_pubkey == new_authority_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "New authority must differ from the current authority".to_string(),
+        ));
+    }
+
+    let instruction = system_instruction::authorize_nonce_account(
+        &nonce_pubkey,
+        &authority_pubkey,
+        &new_authority_pubkey,
+    );
+
+    let response = NonceInstructionResponse {
+        instruction: instruction_response(&instruction),
+        nonce_account: nonce_pubkey.to_string(),
+        authority: new_authority_pubkey.to_string(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Nonce authorize instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn withdraw_nonce(
+    Json(payload): Json<WithdrawNonceRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /nonce/withdraw - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let nonce_pubkey = parse_pubkey(payload.nonce_account.as_ref(), "nonce account")?;
+    let authority_pubkey = parse_pubkey(payload.authority.as_ref(), "authority")?;
+    let destination_pubkey = parse_pubkey(payload.destination.as_ref(), "destination")?;
+
+    let lamports = payload
+        .lamports
+        .filter(|&l| l > 0)
+        .ok_or(SolanaError::MissingFields)?;
+
+    let instruction = system_instruction::withdraw_nonce_account(
+        &nonce_pubkey,
+        &authority_pubkey,
+        &destination_pubkey,
+        lamports,
+    );
+
+    let response = NonceInstructionResponse {
+        instruction: instruction_response(&instruction),
+        nonce_account: nonce_pubkey.to_string(),
+        authority: authority_pubkey.to_string(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Nonce withdraw instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_nonce(Path(address): Path<String>) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /nonce/{}", address);
+
+    let nonce_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid nonce account address".to_string()))?;
+
+    let client = get_rpc_client();
+    let (lamports, data) = fetch_nonce(&client, &nonce_pubkey)?;
+
+    let response = NonceAccountResponse {
+        address: nonce_pubkey.to_string(),
+        lamports,
+        authority: data.authority.to_string(),
+        nonce: data.blockhash().to_string(),
+        lamports_per_signature: data.fee_calculator.lamports_per_signature,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Nonce account fetched successfully");
+
+    Ok(Json(json_response))
+}
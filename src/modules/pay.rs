@@ -0,0 +1,959 @@
+use crate::modules::send::{
+    build_sol_transfer, build_token_transfer, sol_transfer_response, token_transfer_response,
+    SendSolResponse, SendTokenResponse, TokenTransferParams,
+};
+use crate::modules::token::fetch_mint;
+use crate::utils::amount::{format_ui_amount, parse_ui_amount, SOL_DECIMALS};
+use crate::utils::errors::SolanaError;
+use crate::utils::policy::{policy, Transfer};
+use crate::utils::pubkey::{parse_optional_pubkey, parse_pubkey};
+use crate::utils::solana_client::get_rpc_client;
+use axum::{routing::post, Json, Router};
+use base64::{engine::general_purpose, Engine as _};
+use percent_encoding::percent_decode_str;
+use qrcode::{Color, QrCode};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    TransactionConfirmationStatus, UiLoadedAddresses, UiTransactionEncoding,
+    UiTransactionTokenBalance,
+};
+use std::collections::HashMap;
+use tracing::info;
+use url::form_urlencoded;
+
+const SOLANA_PAY_SCHEME: &str = "solana:";
+const DEFAULT_QR_SIZE: u32 = 512;
+const MAX_QR_SIZE: u32 = 2048;
+// Light border around the code, in modules, as required by the QR spec
+const QR_QUIET_ZONE: usize = 4;
+
+#[derive(Deserialize, Serialize)]
+pub struct PayUrlRequest {
+    pub recipient: Option<String>,
+    // Decimal amount in SOL or token units, e.g. "1.5"
+    pub amount: Option<String>,
+    #[serde(rename = "splToken")]
+    pub spl_token: Option<String>,
+    pub reference: Option<Vec<String>>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+    // "png" or "svg"; no QR code is rendered when omitted
+    pub qr: Option<String>,
+    #[serde(rename = "qrSize")]
+    pub qr_size: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayParseRequest {
+    pub url: Option<String>,
+    // Wallet paying the request; the transfer instruction is built when provided
+    pub payer: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayVerifyRequest {
+    pub reference: Option<String>,
+    pub recipient: Option<String>,
+    // Decimal amount in SOL or token units, as in the payment URL
+    pub amount: Option<String>,
+    #[serde(rename = "splToken")]
+    pub spl_token: Option<String>,
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct QrCodeResponse {
+    pub format: String,
+    pub mime_type: String,
+    // Base64 for PNG, markup for SVG
+    pub data: String,
+}
+
+#[derive(Serialize)]
+pub struct PayUrlResponse {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qr: Option<QrCodeResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum PayTransferResponse {
+    Sol(SendSolResponse),
+    Token(SendTokenResponse),
+}
+
+#[derive(Serialize)]
+pub struct PayParseResponse {
+    // "transfer" or "transaction"
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spl_token: Option<String>,
+    pub references: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    // Transaction request endpoint for "transaction" URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<PayTransferResponse>,
+}
+
+#[derive(Serialize)]
+pub struct PayVerifyResponse {
+    pub reference: String,
+    // not_found, invalid, processed, confirmed or finalized
+    pub status: String,
+    pub signature: Option<String>,
+    pub slot: Option<u64>,
+    pub block_time: Option<i64>,
+    pub expected_amount: u64,
+    pub received_amount: Option<u64>,
+    // Why the most recent matching transaction was rejected
+    pub error: Option<String>,
+}
+
+// What a transaction must contain to settle a payment request
+pub struct ExpectedPayment {
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+
+pub enum PaymentCheck {
+    Valid {
+        received: u64,
+        slot: u64,
+        block_time: Option<i64>,
+    },
+    Invalid {
+        reason: String,
+        received: Option<u64>,
+    },
+}
+
+// The fields of a Solana Pay transfer request URL
+pub struct TransferRequest {
+    pub recipient: Pubkey,
+    pub amount: Option<String>,
+    pub spl_token: Option<Pubkey>,
+    pub references: Vec<Pubkey>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+}
+
+pub enum PayUrl {
+    Transfer(TransferRequest),
+    Transaction {
+        link: String,
+        label: Option<String>,
+        message: Option<String>,
+    },
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/pay/url", post(create_pay_url))
+        .route("/pay/parse", post(parse_pay_url_handler))
+        .route("/pay/verify", post(verify_payment))
+}
+
+// Validates a decimal amount against the asset's decimals; returns the canonical form and base units
+pub fn normalize_amount(amount: &str, decimals: u8) -> Result<(String, u64), SolanaError> {
+    let raw_amount = parse_ui_amount(amount, decimals)?;
+
+    Ok((format_ui_amount(raw_amount, decimals), raw_amount))
+}
+
+pub fn encode_transfer_request(request: &TransferRequest) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+
+    if let Some(amount) = &request.amount {
+        query.append_pair("amount", amount);
+    }
+    if let Some(spl_token) = &request.spl_token {
+        query.append_pair("spl-token", &spl_token.to_string());
+    }
+    for reference in &request.references {
+        query.append_pair("reference", &reference.to_string());
+    }
+    if let Some(label) = &request.label {
+        query.append_pair("label", label);
+    }
+    if let Some(message) = &request.message {
+        query.append_pair("message", message);
+    }
+    if let Some(memo) = &request.memo {
+        query.append_pair("memo", memo);
+    }
+
+    let query = query.finish();
+    if query.is_empty() {
+        format!("{SOLANA_PAY_SCHEME}{}", request.recipient)
+    } else {
+        format!("{SOLANA_PAY_SCHEME}{}?{query}", request.recipient)
+    }
+}
+
+pub fn parse_pay_url(url: &str) -> Result<PayUrl, SolanaError> {
+    let url = url.trim();
+
+    let rest = url
+        .get(..SOLANA_PAY_SCHEME.len())
+        .filter(|scheme| scheme.eq_ignore_ascii_case(SOLANA_PAY_SCHEME))
+        .map(|_| &url[SOLANA_PAY_SCHEME.len()..])
+        .ok_or_else(|| SolanaError::InvalidInput("URL must use the solana: scheme".to_string()))?;
+
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let mut amount = None;
+    let mut spl_token = None;
+    let mut references = Vec::new();
+    let mut label = None;
+    let mut message = None;
+    let mut memo = None;
+
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        let slot = match key.as_ref() {
+            "amount" => &mut amount,
+            "spl-token" => &mut spl_token,
+            "label" => &mut label,
+            "message" => &mut message,
+            "memo" => &mut memo,
+            "reference" => {
+                references.push(value.parse::<Pubkey>().map_err(|_| {
+                    SolanaError::InvalidInput(format!("Invalid reference: {value}"))
+                })?);
+                continue;
+            }
+            // Unknown parameters are ignored for forward compatibility
+            _ => continue,
+        };
+
+        if slot.replace(value.into_owned()).is_some() {
+            return Err(SolanaError::InvalidInput(format!(
+                "Parameter {key} appears more than once"
+            )));
+        }
+    }
+
+    // Transaction requests carry a URL-encoded https link instead of a recipient
+    let decoded_path = percent_decode_str(path)
+        .decode_utf8()
+        .map_err(|_| SolanaError::InvalidInput("Invalid URL encoding".to_string()))?;
+
+    if decoded_path.starts_with("https://") {
+        return Ok(PayUrl::Transaction {
+            link: decoded_path.into_owned(),
+            label,
+            message,
+        });
+    }
+
+    let recipient = path
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid recipient address".to_string()))?;
+
+    let spl_token = spl_token
+        .map(|mint| {
+            mint.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid spl-token mint".to_string()))
+        })
+        .transpose()?;
+
+    Ok(PayUrl::Transfer(TransferRequest {
+        recipient,
+        amount,
+        spl_token,
+        references,
+        label,
+        message,
+        memo,
+    }))
+}
+
+fn render_png(code: &QrCode, size: u32) -> Result<Vec<u8>, SolanaError> {
+    let width = code.width();
+    let modules = width + 2 * QR_QUIET_ZONE;
+    let scale = (size as usize / modules).max(1);
+    let dimension = modules * scale;
+
+    // 8-bit grayscale, white background
+    let mut pixels = vec![255u8; dimension * dimension];
+    for (index, color) in code.to_colors().into_iter().enumerate() {
+        if color != Color::Dark {
+            continue;
+        }
+
+        let x = (index % width + QR_QUIET_ZONE) * scale;
+        let y = (index / width + QR_QUIET_ZONE) * scale;
+        for row in y..y + scale {
+            pixels[row * dimension + x..row * dimension + x + scale].fill(0);
+        }
+    }
+
+    let png_error =
+        |e: png::EncodingError| SolanaError::InvalidInput(format!("Failed to render QR code: {e}"));
+
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, dimension as u32, dimension as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(png_error)?;
+    writer.write_image_data(&pixels).map_err(png_error)?;
+    writer.finish().map_err(png_error)?;
+
+    Ok(bytes)
+}
+
+pub fn render_qr_code(
+    data: &str,
+    format: &str,
+    size: Option<u32>,
+) -> Result<QrCodeResponse, SolanaError> {
+    let size = size.unwrap_or(DEFAULT_QR_SIZE);
+    if size == 0 || size > MAX_QR_SIZE {
+        return Err(SolanaError::InvalidInput(format!(
+            "qrSize must be between 1 and {MAX_QR_SIZE}"
+        )));
+    }
+
+    let code = QrCode::new(data.as_bytes())
+        .map_err(|e| SolanaError::InvalidInput(format!("Failed to encode QR code: {e}")))?;
+
+    match format {
+        "png" => Ok(QrCodeResponse {
+            format: "png".to_string(),
+            mime_type: "image/png".to_string(),
+            data: general_purpose::STANDARD.encode(render_png(&code, size)?),
+        }),
+        "svg" => Ok(QrCodeResponse {
+            format: "svg".to_string(),
+            mime_type: "image/svg+xml".to_string(),
+            data: code
+                .render::<qrcode::render::svg::Color>()
+                .min_dimensions(size, size)
+                .build(),
+        }),
+        _ => Err(SolanaError::InvalidInput(
+            "qr must be png or svg".to_string(),
+        )),
+    }
+}
+
+async fn create_pay_url(
+    Json(payload): Json<PayUrlRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /pay/url - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let recipient_pubkey = parse_pubkey(payload.recipient.as_ref(), "recipient")?;
+    let spl_token_pubkey = parse_optional_pubkey(payload.spl_token.as_ref(), "spl-token mint")?;
+
+    let references = payload
+        .reference
+        .iter()
+        .flatten()
+        .map(|reference| {
+            reference
+                .parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput(format!("Invalid reference: {reference}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let amount = match payload.amount.as_ref().filter(|s| !s.trim().is_empty()) {
+        Some(amount) => {
+            let decimals = match &spl_token_pubkey {
+                Some(mint_pubkey) => fetch_mint(&get_rpc_client(), mint_pubkey)?.base.decimals,
+                None => SOL_DECIMALS,
+            };
+            Some(normalize_amount(amount, decimals)?.0)
+        }
+        None => None,
+    };
+
+    let non_empty = |value: &Option<String>| value.clone().filter(|s| !s.is_empty());
+
+    let request = TransferRequest {
+        recipient: recipient_pubkey,
+        amount,
+        spl_token: spl_token_pubkey,
+        references,
+        label: non_empty(&payload.label),
+        message: non_empty(&payload.message),
+        memo: non_empty(&payload.memo),
+    };
+
+    let url = encode_transfer_request(&request);
+
+    let qr = payload
+        .qr
+        .as_deref()
+        .map(|format| render_qr_code(&url, format, payload.qr_size))
+        .transpose()?;
+
+    info!("Created Solana Pay transfer request: {}", url);
+
+    let response = PayUrlResponse { url, qr };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Solana Pay URL created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn parse_pay_url_handler(
+    Json(payload): Json<PayParseRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /pay/parse - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let url = payload
+        .url
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let payer_pubkey = parse_optional_pubkey(payload.payer.as_ref(), "payer")?;
+
+    let request = match parse_pay_url(url)? {
+        PayUrl::Transaction {
+            link,
+            label,
+            message,
+        } => {
+            let response = PayParseResponse {
+                kind: "transaction".to_string(),
+                recipient: None,
+                amount: None,
+                spl_token: None,
+                references: Vec::new(),
+                label,
+                message,
+                memo: None,
+                link: Some(link),
+                transfer: None,
+            };
+
+            info!("Response: 200 - Solana Pay transaction request parsed successfully");
+
+            return Ok(Json(serde_json::json!({
+                "success": true,
+                "data": response
+            })));
+        }
+        PayUrl::Transfer(request) => request,
+    };
+
+    let client = get_rpc_client();
+
+    let mint_state = request
+        .spl_token
+        .map(|mint_pubkey| fetch_mint(&client, &mint_pubkey))
+        .transpose()?;
+    let decimals = mint_state
+        .as_ref()
+        .map_or(SOL_DECIMALS, |mint| mint.base.decimals);
+
+    let amount = request
+        .amount
+        .as_deref()
+        .map(|amount| normalize_amount(amount, decimals))
+        .transpose()?;
+
+    // The transfer can only be built once both the payer and the amount are known
+    let transfer = match (payer_pubkey, &amount) {
+        (Some(payer_pubkey), Some((_, raw_amount))) => {
+            let raw_amount = *raw_amount;
+            if raw_amount == 0 {
+                return Err(SolanaError::InvalidInput(
+                    "Amount must be greater than zero".to_string(),
+                ));
+            }
+
+            let transfer = match (request.spl_token, &mint_state) {
+                (Some(mint_pubkey), Some(mint_state)) => {
+                    // Solana Pay requires TransferChecked for SPL tokens
+                    let transfer = build_token_transfer(
+                        &client,
+                        TokenTransferParams {
+                            owner: payer_pubkey,
+                            destination: request.recipient,
+                            mint: mint_pubkey,
+                            mint_state,
+                            amount: raw_amount,
+                            multisig_signers: &[],
+                            memo: request.memo.as_ref(),
+                            memo_signers: None,
+                            references: &request.references,
+                            checked: true,
+                        },
+                    )?;
+                    PayTransferResponse::Token(token_transfer_response(&transfer))
+                }
+                _ => {
+                    let transfer = build_sol_transfer(
+                        &payer_pubkey,
+                        &request.recipient,
+                        raw_amount,
+                        request.memo.as_ref(),
+                        None,
+                        &request.references,
+                    )?;
+                    PayTransferResponse::Sol(sol_transfer_response(&transfer))
+                }
+            };
+
+            // Parsing moves no funds, so velocity is not consumed here
+            policy()
+                .check_transfer(
+                    "/pay/parse",
+                    &Transfer {
+                        recipient: request.recipient,
+                        mint: request.spl_token,
+                        amount: raw_amount,
+                    },
+                )
+                .map_err(SolanaError::PolicyViolation)?;
+
+            Some(transfer)
+        }
+        _ => None,
+    };
+
+    let response = PayParseResponse {
+        kind: "transfer".to_string(),
+        recipient: Some(request.recipient.to_string()),
+        amount: amount.map(|(amount, _)| amount),
+        spl_token: request.spl_token.map(|mint| mint.to_string()),
+        references: request
+            .references
+            .iter()
+            .map(|reference| reference.to_string())
+            .collect(),
+        label: request.label,
+        message: request.message,
+        memo: request.memo,
+        link: None,
+        transfer,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Solana Pay URL parsed successfully");
+
+    Ok(Json(json_response))
+}
+
+fn invalid(reason: impl Into<String>) -> PaymentCheck {
+    PaymentCheck::Invalid {
+        reason: reason.into(),
+        received: None,
+    }
+}
+
+// Checks a transaction against the expected recipient, amount and memo,
+// following the Solana Pay validateTransfer rules
+pub fn check_payment(
+    client: &RpcClient,
+    signature: &Signature,
+    expected: &ExpectedPayment,
+) -> Result<PaymentCheck, SolanaError> {
+    let transaction = client.get_transaction_with_config(
+        signature,
+        RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        },
+    )?;
+
+    let slot = transaction.slot;
+    let block_time = transaction.block_time;
+
+    let Some(meta) = transaction.transaction.meta else {
+        return Ok(invalid("Transaction metadata is unavailable"));
+    };
+    if meta.err.is_some() {
+        return Ok(invalid("Transaction failed"));
+    }
+
+    let Some(decoded) = transaction.transaction.transaction.decode() else {
+        return Ok(invalid("Transaction could not be decoded"));
+    };
+
+    // Static keys followed by keys loaded from lookup tables, as indexed by the balances
+    let mut account_keys = decoded.message.static_account_keys().to_vec();
+    if let Some(loaded) = Option::<UiLoadedAddresses>::from(meta.loaded_addresses.clone()) {
+        account_keys.extend(
+            loaded
+                .writable
+                .iter()
+                .chain(&loaded.readonly)
+                .filter_map(|key| key.parse::<Pubkey>().ok()),
+        );
+    }
+
+    if let Some(memo) = &expected.memo {
+        let has_memo = decoded.message.instructions().iter().any(|instruction| {
+            account_keys
+                .get(instruction.program_id_index as usize)
+                .is_some_and(|program_id| {
+                    *program_id == spl_memo::id() || *program_id == spl_memo::v1::id()
+                })
+                && instruction.data == memo.as_bytes()
+        });
+
+        if !has_memo {
+            return Ok(invalid("Memo does not match"));
+        }
+    }
+
+    let received = match expected.mint {
+        None => {
+            let Some(index) = account_keys
+                .iter()
+                .position(|key| *key == expected.recipient)
+            else {
+                return Ok(invalid("Recipient is not part of the transaction"));
+            };
+
+            let pre = meta.pre_balances.get(index).copied().unwrap_or_default();
+            let post = meta.post_balances.get(index).copied().unwrap_or_default();
+            post.saturating_sub(pre)
+        }
+        Some(mint) => {
+            let recipient = expected.recipient.to_string();
+            let mint = mint.to_string();
+
+            // Token balance change per account owned by the recipient for this mint
+            let matching = |balances: Option<Vec<UiTransactionTokenBalance>>| {
+                balances
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|balance| {
+                        balance.mint == mint
+                            && Option::<String>::from(balance.owner.clone()).as_ref()
+                                == Some(&recipient)
+                    })
+                    .map(|balance| {
+                        (
+                            balance.account_index,
+                            balance.ui_token_amount.amount.parse::<u64>().unwrap_or(0),
+                        )
+                    })
+                    .collect::<HashMap<u8, u64>>()
+            };
+
+            let pre = matching(meta.pre_token_balances.into());
+            let post = matching(meta.post_token_balances.into());
+
+            if post.is_empty() {
+                return Ok(invalid(
+                    "Recipient token account is not part of the transaction",
+                ));
+            }
+
+            post.iter()
+                .map(|(index, amount)| {
+                    amount.saturating_sub(pre.get(index).copied().unwrap_or_default())
+                })
+                .sum()
+        }
+    };
+
+    if received < expected.amount {
+        return Ok(PaymentCheck::Invalid {
+            reason: format!(
+                "Recipient received {received}, expected at least {}",
+                expected.amount
+            ),
+            received: Some(received),
+        });
+    }
+
+    Ok(PaymentCheck::Valid {
+        received,
+        slot,
+        block_time,
+    })
+}
+
+async fn verify_payment(
+    Json(payload): Json<PayVerifyRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /pay/verify - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let amount = payload
+        .amount
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    // Parse public keys AFTER validation
+    let reference_pubkey = parse_pubkey(payload.reference.as_ref(), "reference")?;
+    let recipient_pubkey = parse_pubkey(payload.recipient.as_ref(), "recipient")?;
+    let mint_pubkey = parse_optional_pubkey(payload.spl_token.as_ref(), "spl-token mint")?;
+
+    let client = get_rpc_client();
+
+    let decimals = match &mint_pubkey {
+        Some(mint_pubkey) => fetch_mint(&client, mint_pubkey)?.base.decimals,
+        None => SOL_DECIMALS,
+    };
+
+    let expected = ExpectedPayment {
+        recipient: recipient_pubkey,
+        mint: mint_pubkey,
+        amount: normalize_amount(amount, decimals)?.1,
+        memo: payload.memo.clone().filter(|memo| !memo.is_empty()),
+    };
+
+    let signatures = client.get_signatures_for_address_with_config(
+        &reference_pubkey,
+        GetConfirmedSignaturesForAddress2Config {
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        },
+    )?;
+
+    let mut response = PayVerifyResponse {
+        reference: reference_pubkey.to_string(),
+        status: "not_found".to_string(),
+        signature: None,
+        slot: None,
+        block_time: None,
+        expected_amount: expected.amount,
+        received_amount: None,
+        error: None,
+    };
+
+    // Oldest first: the first valid transaction is the one that settled the request
+    for signature_info in signatures.iter().rev() {
+        let signature = signature_info
+            .signature
+            .parse::<Signature>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid signature from RPC".to_string()))?;
+
+        response.signature = Some(signature_info.signature.clone());
+        response.slot = Some(signature_info.slot);
+        response.block_time = signature_info.block_time;
+
+        if signature_info.err.is_some() {
+            response.status = "invalid".to_string();
+            response.error = Some("Transaction failed".to_string());
+            continue;
+        }
+
+        match check_payment(&client, &signature, &expected)? {
+            PaymentCheck::Valid {
+                received,
+                slot,
+                block_time,
+            } => {
+                response.status = match signature_info.confirmation_status {
+                    Some(TransactionConfirmationStatus::Finalized) => "finalized",
+                    Some(TransactionConfirmationStatus::Processed) => "processed",
+                    _ => "confirmed",
+                }
+                .to_string();
+                response.slot = Some(slot);
+                response.block_time = block_time;
+                response.received_amount = Some(received);
+                response.error = None;
+                break;
+            }
+            PaymentCheck::Invalid { reason, received } => {
+                response.status = "invalid".to_string();
+                response.received_amount = received;
+                response.error = Some(reason);
+            }
+        }
+    }
+
+    info!(
+        "Payment for reference {} is {} ({} candidate transactions)",
+        reference_pubkey,
+        response.status,
+        signatures.len()
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Payment verification completed");
+
+    Ok(Json(json_response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_request(recipient: Pubkey) -> TransferRequest {
+        TransferRequest {
+            recipient,
+            amount: None,
+            spl_token: None,
+            references: Vec::new(),
+            label: None,
+            message: None,
+            memo: None,
+        }
+    }
+
+    fn parse_transfer(url: &str) -> TransferRequest {
+        match parse_pay_url(url) {
+            Ok(PayUrl::Transfer(request)) => request,
+            Ok(PayUrl::Transaction { .. }) => panic!("expected a transfer request"),
+            Err(_) => panic!("failed to parse {url}"),
+        }
+    }
+
+    fn invalid_input(url: &str) -> String {
+        match parse_pay_url(url) {
+            Err(SolanaError::InvalidInput(message)) => message,
+            _ => panic!("expected {url} to be rejected"),
+        }
+    }
+
+    #[test]
+    fn encode_transfer_request_round_trips() {
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let references = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let url = encode_transfer_request(&TransferRequest {
+            amount: Some("1.25".to_string()),
+            spl_token: Some(mint),
+            references: references.clone(),
+            label: Some("Coffee & Cake".to_string()),
+            message: Some("Order #42 = 100% paid?".to_string()),
+            memo: Some("ünïcode ✓".to_string()),
+            ..transfer_request(recipient)
+        });
+        let request = parse_transfer(&url);
+
+        assert_eq!(request.recipient, recipient);
+        assert_eq!(request.amount.as_deref(), Some("1.25"));
+        assert_eq!(request.spl_token, Some(mint));
+        assert_eq!(request.references, references);
+        assert_eq!(request.label.as_deref(), Some("Coffee & Cake"));
+        assert_eq!(request.message.as_deref(), Some("Order #42 = 100% paid?"));
+        assert_eq!(request.memo.as_deref(), Some("ünïcode ✓"));
+    }
+
+    #[test]
+    fn encode_transfer_request_percent_encodes_values() {
+        let recipient = Pubkey::new_unique();
+
+        assert_eq!(
+            encode_transfer_request(&transfer_request(recipient)),
+            format!("solana:{recipient}")
+        );
+
+        let url = encode_transfer_request(&TransferRequest {
+            amount: Some("0.5".to_string()),
+            label: Some("Coffee & Cake".to_string()),
+            message: Some("a=b?#/%".to_string()),
+            ..transfer_request(recipient)
+        });
+        assert_eq!(
+            url,
+            format!(
+                "solana:{recipient}?amount=0.5&label=Coffee+%26+Cake&message=a%3Db%3F%23%2F%25"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_pay_url_decodes_percent_encoded_values() {
+        let recipient = Pubkey::new_unique();
+
+        let request = parse_transfer(&format!(
+            "SOLANA:{recipient}?label=Caf%C3%A9%20Bar&message=Thanks+a+lot%21&unknown=ignored"
+        ));
+        assert_eq!(request.recipient, recipient);
+        assert_eq!(request.label.as_deref(), Some("Café Bar"));
+        assert_eq!(request.message.as_deref(), Some("Thanks a lot!"));
+        assert!(request.amount.is_none());
+        assert!(request.references.is_empty());
+    }
+
+    #[test]
+    fn parse_pay_url_recognizes_transaction_requests() {
+        let url = "solana:https%3A%2F%2Fexample.com%2Fpay%3Fid%3D1?label=Shop&message=Hi%20there";
+
+        match parse_pay_url(url) {
+            Ok(PayUrl::Transaction {
+                link,
+                label,
+                message,
+            }) => {
+                assert_eq!(link, "https://example.com/pay?id=1");
+                assert_eq!(label.as_deref(), Some("Shop"));
+                assert_eq!(message.as_deref(), Some("Hi there"));
+            }
+            _ => panic!("expected a transaction request"),
+        }
+    }
+
+    #[test]
+    fn parse_pay_url_rejects_invalid_urls() {
+        let recipient = Pubkey::new_unique();
+
+        assert_eq!(
+            invalid_input(&format!("bitcoin:{recipient}")),
+            "URL must use the solana: scheme"
+        );
+        assert_eq!(invalid_input("solana:"), "Invalid recipient address");
+        assert_eq!(
+            invalid_input("solana:not-a-pubkey?amount=1"),
+            "Invalid recipient address"
+        );
+        assert_eq!(
+            invalid_input(&format!("solana:{recipient}?spl-token=bad")),
+            "Invalid spl-token mint"
+        );
+        assert_eq!(
+            invalid_input(&format!("solana:{recipient}?reference=bad")),
+            "Invalid reference: bad"
+        );
+        assert_eq!(
+            invalid_input(&format!("solana:{recipient}?amount=1&amount=2")),
+            "Parameter amount appears more than once"
+        );
+        assert_eq!(invalid_input("solana:%FF"), "Invalid URL encoding");
+    }
+}
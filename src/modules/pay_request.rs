@@ -0,0 +1,257 @@
+use crate::modules::pay::normalize_amount;
+use crate::modules::send::{
+    build_sol_transfer, build_token_transfer, encode_transaction, TokenTransferParams,
+};
+use crate::modules::token::fetch_mint;
+use crate::utils::amount::SOL_DECIMALS;
+use crate::utils::errors::SolanaError;
+use crate::utils::policy::{api_key, policy, Transfer};
+use crate::utils::pubkey::parse_pubkey;
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::{Path, RawQuery},
+    http::HeaderMap,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::{env, fs};
+use tracing::info;
+use url::form_urlencoded;
+
+const MAX_REFERENCES: usize = 8;
+
+static TEMPLATES: OnceLock<HashMap<String, PayTemplate>> = OnceLock::new();
+
+// A payment a wallet can request a transaction for, keyed by name in PAY_TEMPLATES
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PayTemplate {
+    pub label: String,
+    pub icon: String,
+    pub recipient: String,
+    // None for SOL
+    pub spl_token: Option<String>,
+    // Decimal amount; when omitted the link must carry an `amount` query parameter
+    pub amount: Option<String>,
+    pub memo: Option<String>,
+    // Shown by the wallet alongside the transaction
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayTransactionRequest {
+    pub account: Option<String>,
+}
+
+// Field names follow the Solana Pay transaction request spec
+#[derive(Serialize)]
+pub struct PayTemplateInfoResponse {
+    pub label: String,
+    pub icon: String,
+}
+
+#[derive(Serialize)]
+pub struct PayTransactionResponse {
+    pub transaction: String, // Base64 encoded, unsigned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+// Query parameters of the transaction request link
+struct LinkParams {
+    references: Vec<Pubkey>,
+    amount: Option<String>,
+}
+
+pub fn routes() -> Router {
+    Router::new().route(
+        "/pay/request/:template",
+        get(get_template_info).post(create_transaction),
+    )
+}
+
+// Loads the transaction request templates from PAY_TEMPLATES (a JSON file); none are served without one
+pub fn init_pay_templates() -> Result<(), String> {
+    let templates: HashMap<String, PayTemplate> = match env::var("PAY_TEMPLATES") {
+        Ok(path) => {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read pay templates {path}: {e}"))?;
+            info!("Loaded pay templates from {path}");
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("Invalid pay templates {path}: {e}"))?
+        }
+        Err(_) => HashMap::new(),
+    };
+
+    for (name, template) in &templates {
+        let addresses = [Some(&template.recipient), template.spl_token.as_ref()];
+        for address in addresses.into_iter().flatten() {
+            address
+                .parse::<Pubkey>()
+                .map_err(|_| format!("Invalid address in pay template {name}: {address}"))?;
+        }
+    }
+
+    TEMPLATES
+        .set(templates)
+        .map_err(|_| "Pay templates already initialized".to_string())
+}
+
+fn find_template(name: &str) -> Result<&'static PayTemplate, SolanaError> {
+    TEMPLATES
+        .get_or_init(HashMap::new)
+        .get(name)
+        .ok_or_else(|| SolanaError::InvalidInput(format!("Unknown pay template: {name}")))
+}
+
+fn parse_link_params(query: Option<&str>) -> Result<LinkParams, SolanaError> {
+    let mut params = LinkParams {
+        references: Vec::new(),
+        amount: None,
+    };
+
+    for (key, value) in form_urlencoded::parse(query.unwrap_or_default().as_bytes()) {
+        match key.as_ref() {
+            "reference" => params.references.push(
+                value
+                    .parse::<Pubkey>()
+                    .map_err(|_| SolanaError::InvalidInput("Invalid reference".to_string()))?,
+            ),
+            "amount" => params.amount = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    if params.references.len() > MAX_REFERENCES {
+        return Err(SolanaError::InvalidInput(format!(
+            "At most {MAX_REFERENCES} references are allowed"
+        )));
+    }
+
+    Ok(params)
+}
+
+async fn get_template_info(
+    Path(template_name): Path<String>,
+) -> Result<Json<PayTemplateInfoResponse>, SolanaError> {
+    info!("GET /pay/request/{}", template_name);
+
+    let template = find_template(&template_name)?;
+
+    info!("Response: 200 - Pay template info fetched successfully");
+
+    Ok(Json(PayTemplateInfoResponse {
+        label: template.label.clone(),
+        icon: template.icon.clone(),
+    }))
+}
+
+async fn create_transaction(
+    Path(template_name): Path<String>,
+    RawQuery(query): RawQuery,
+    headers: HeaderMap,
+    Json(payload): Json<PayTransactionRequest>,
+) -> Result<Json<PayTransactionResponse>, SolanaError> {
+    info!(
+        "POST /pay/request/{} - Request: {}",
+        template_name,
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let template = find_template(&template_name)?;
+    let link_params = parse_link_params(query.as_deref())?;
+
+    // Parse public keys AFTER validation
+    let payer_pubkey = parse_pubkey(payload.account.as_ref(), "account")?;
+    let recipient_pubkey = template
+        .recipient
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid recipient address".to_string()))?;
+    let mint_pubkey = template
+        .spl_token
+        .as_ref()
+        .map(|mint| mint.parse::<Pubkey>())
+        .transpose()
+        .map_err(|_| SolanaError::InvalidInput("Invalid spl-token mint address".to_string()))?;
+
+    let amount = template
+        .amount
+        .as_ref()
+        .or(link_params.amount.as_ref())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let client = get_rpc_client();
+
+    let mint_state = mint_pubkey
+        .map(|mint_pubkey| fetch_mint(&client, &mint_pubkey))
+        .transpose()?;
+    let decimals = mint_state
+        .as_ref()
+        .map_or(SOL_DECIMALS, |mint| mint.base.decimals);
+
+    let (ui_amount, raw_amount) = normalize_amount(amount, decimals)?;
+    if raw_amount == 0 {
+        return Err(SolanaError::InvalidInput(
+            "Amount must be greater than zero".to_string(),
+        ));
+    }
+
+    let transfer = match (mint_pubkey, &mint_state) {
+        (Some(mint_pubkey), Some(mint_state)) => build_token_transfer(
+            &client,
+            TokenTransferParams {
+                owner: payer_pubkey,
+                destination: recipient_pubkey,
+                mint: mint_pubkey,
+                mint_state,
+                amount: raw_amount,
+                multisig_signers: &[],
+                memo: template.memo.as_ref(),
+                memo_signers: None,
+                references: &link_params.references,
+                checked: true,
+            },
+        )?,
+        _ => build_sol_transfer(
+            &payer_pubkey,
+            &recipient_pubkey,
+            raw_amount,
+            template.memo.as_ref(),
+            None,
+            &link_params.references,
+        )?,
+    };
+
+    let blockhash = client.get_latest_blockhash()?;
+
+    // The paying wallet is the fee payer and the only signer
+    let (transaction, size) =
+        encode_transaction(&transfer.into_instructions(), &payer_pubkey, blockhash)?;
+
+    policy().enforce(
+        "/pay/request",
+        api_key(&headers),
+        &[Transfer {
+            recipient: recipient_pubkey,
+            mint: mint_pubkey,
+            amount: raw_amount,
+        }],
+    )?;
+
+    info!(
+        "Built {} transaction for {} ({} bytes): {} to {}",
+        template_name, payer_pubkey, size, ui_amount, recipient_pubkey
+    );
+
+    info!("Response: 200 - Pay transaction created successfully");
+
+    Ok(Json(PayTransactionResponse {
+        transaction,
+        message: template.message.clone(),
+    }))
+}
@@ -1,17 +1,54 @@
+use crate::modules::memo::build_memo_instruction;
+use crate::modules::nonce::{resolve_durable_nonce, UseNonceRequest};
+use crate::modules::token::{
+    fetch_mint, fetch_token_account, instruction_response, parse_multisig_signers,
+    InstructionResponse, TokenState,
+};
+use crate::utils::amount::{format_ui_amount, resolve_amount, SOL_DECIMALS};
 use crate::utils::errors::SolanaError;
-use axum::{routing::post, Json, Router};
+use crate::utils::policy::{api_key, policy, Transfer};
+use crate::utils::solana_client::get_rpc_client;
+use axum::{http::HeaderMap, routing::post, Json, Router};
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
-use solana_sdk::{pubkey::Pubkey, system_instruction};
-use spl_associated_token_account::get_associated_token_address;
+use solana_account_decoder::parse_token_extension::UiExtension;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
 use spl_token::instruction::transfer;
+use spl_token_2022::state::Mint;
+use std::collections::{hash_map::Entry, HashMap};
 use tracing::info;
 
+// Compute unit estimates used when packing payouts into transactions
+const MAX_TRANSACTION_COMPUTE_UNITS: u32 = 1_400_000;
+const COMPUTE_BUDGET_UNITS: u32 = 150;
+const SOL_TRANSFER_UNITS: u32 = 150;
+const CREATE_ATA_UNITS: u32 = 30_000;
+const TOKEN_TRANSFER_UNITS: u32 = 8_000;
+
+const MAX_BATCH_PAYOUTS: usize = 1_000;
+
 #[derive(Deserialize, Serialize)]
 pub struct SendSolRequest {
     pub from: Option<String>,
     pub to: Option<String>,
     pub lamports: Option<u64>,
+    pub memo: Option<String>,
+    #[serde(rename = "memoSigners")]
+    pub memo_signers: Option<Vec<String>>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -20,6 +57,100 @@ pub struct SendTokenRequest {
     pub mint: Option<String>,
     pub owner: Option<String>,
     pub amount: Option<u64>,
+    // Decimal alternative to `amount`, converted using the mint's decimals
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    // Mint decimals; checked against the on-chain mint when provided
+    pub decimals: Option<u8>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+    pub memo: Option<String>,
+    #[serde(rename = "memoSigners")]
+    pub memo_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BatchPayoutItem {
+    pub recipient: Option<String>,
+    pub amount: Option<u64>,
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    // Omitted for SOL payouts
+    pub mint: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BatchPayoutRequest {
+    #[serde(rename = "feePayer")]
+    pub fee_payer: Option<String>,
+    // Source of the funds, defaults to the fee payer
+    pub sender: Option<String>,
+    pub payouts: Option<Vec<BatchPayoutItem>>,
+    // Builds a single transaction against a durable nonce instead of a recent blockhash
+    #[serde(rename = "useNonce")]
+    pub use_nonce: Option<UseNonceRequest>,
+}
+
+#[derive(Serialize)]
+pub struct BatchTransactionResponse {
+    pub transaction: String, // Base64 encoded, unsigned
+    pub recipients: Vec<usize>,
+    pub instruction_count: usize,
+    pub size: usize,
+    pub compute_units: u32,
+}
+
+#[derive(Serialize)]
+pub struct BatchPayoutResponse {
+    pub fee_payer: String,
+    pub sender: String,
+    pub recent_blockhash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce_account: Option<String>,
+    pub transactions: Vec<BatchTransactionResponse>,
+}
+
+// A single validated payout; `mint` is None for SOL
+pub struct Payout {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+}
+
+// A group of payouts that fits into one transaction
+pub struct PlannedTransaction {
+    pub instructions: Vec<Instruction>,
+    pub recipients: Vec<usize>,
+    pub compute_units: u32,
+}
+
+// A transfer and the memo that must be placed immediately before it
+pub struct TransferInstructions {
+    pub memo: Option<Instruction>,
+    pub transfer: Instruction,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl TransferInstructions {
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        self.memo.into_iter().chain([self.transfer]).collect()
+    }
+}
+
+pub struct TokenTransferParams<'a> {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub mint_state: &'a TokenState<Mint>,
+    pub amount: u64,
+    pub multisig_signers: &'a [Pubkey],
+    pub memo: Option<&'a String>,
+    pub memo_signers: Option<&'a Vec<String>>,
+    // Extra read-only accounts used to look the payment up later
+    pub references: &'a [Pubkey],
+    // Use transfer_checked for SPL Token mints too (always used for Token-2022)
+    pub checked: bool,
 }
 
 #[derive(Serialize)]
@@ -27,6 +158,9 @@ pub struct SendSolResponse {
     pub program_id: String,
     pub accounts: Vec<String>,
     pub instruction_data: String,
+    // Place immediately before the transfer instruction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo_instruction: Option<InstructionResponse>,
 }
 
 #[derive(Serialize)]
@@ -41,15 +175,358 @@ pub struct SendTokenResponse {
     pub program_id: String,
     pub accounts: Vec<AccountMetaTokenResponse>,
     pub instruction_data: String,
+    pub amount: u64,
+    pub ui_amount: String,
+    pub decimals: u8,
+    // Place immediately before the transfer instruction, as Token-2022 required memos expect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo_instruction: Option<InstructionResponse>,
 }
 
 pub fn routes() -> Router {
     Router::new()
         .route("/send/sol", post(send_sol))
         .route("/send/token", post(send_token))
+        .route("/send/batch", post(send_batch))
+}
+
+fn serialized_transaction_size(instructions: &[Instruction], fee_payer: &Pubkey) -> usize {
+    let transaction = Transaction::new_unsigned(Message::new(instructions, Some(fee_payer)));
+    bincode::serialized_size(&transaction).unwrap_or(u64::MAX) as usize
+}
+
+fn payout_instructions(
+    payout: &Payout,
+    fee_payer: &Pubkey,
+    sender: &Pubkey,
+    mints: &HashMap<Pubkey, TokenState<Mint>>,
+) -> Result<(Vec<Instruction>, u32), SolanaError> {
+    let Some(mint_pubkey) = payout.mint else {
+        return Ok((
+            vec![system_instruction::transfer(
+                sender,
+                &payout.recipient,
+                payout.amount,
+            )],
+            SOL_TRANSFER_UNITS,
+        ));
+    };
+
+    let mint = mints
+        .get(&mint_pubkey)
+        .ok_or_else(|| SolanaError::InvalidInput(format!("Unknown mint: {mint_pubkey}")))?;
+
+    let source_ata =
+        get_associated_token_address_with_program_id(sender, &mint_pubkey, &mint.program_id);
+    let destination_ata = get_associated_token_address_with_program_id(
+        &payout.recipient,
+        &mint_pubkey,
+        &mint.program_id,
+    );
+
+    // Creating the recipient ATA is a no-op when it already exists
+    let create_ata = create_associated_token_account_idempotent(
+        fee_payer,
+        &payout.recipient,
+        &mint_pubkey,
+        &mint.program_id,
+    );
+
+    let transfer = spl_token_2022::instruction::transfer_checked(
+        &mint.program_id,
+        &source_ata,
+        &mint_pubkey,
+        &destination_ata,
+        sender,
+        &[],
+        payout.amount,
+        mint.base.decimals,
+    )
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    Ok((
+        vec![create_ata, transfer],
+        CREATE_ATA_UNITS + TOKEN_TRANSFER_UNITS,
+    ))
+}
+
+// Greedily packs payouts into the fewest transactions that stay within the
+// packet size and compute limits. `prefix` instructions (e.g. a nonce advance)
+// are placed at the start of every transaction.
+pub fn plan_batch(
+    payouts: &[Payout],
+    fee_payer: &Pubkey,
+    sender: &Pubkey,
+    mints: &HashMap<Pubkey, TokenState<Mint>>,
+    prefix: &[Instruction],
+) -> Result<Vec<PlannedTransaction>, SolanaError> {
+    let mut planned = Vec::new();
+
+    let mut instructions: Vec<Instruction> = Vec::new();
+    let mut recipients: Vec<usize> = Vec::new();
+    let mut compute_units = COMPUTE_BUDGET_UNITS;
+
+    let finish = |instructions: Vec<Instruction>, recipients: Vec<usize>, compute_units: u32| {
+        let mut all = prefix.to_vec();
+        all.push(ComputeBudgetInstruction::set_compute_unit_limit(
+            compute_units,
+        ));
+        all.extend(instructions);
+        PlannedTransaction {
+            instructions: all,
+            recipients,
+            compute_units,
+        }
+    };
+
+    for (index, payout) in payouts.iter().enumerate() {
+        let (group, group_units) = payout_instructions(payout, fee_payer, sender, mints)?;
+
+        let fits = |instructions: &[Instruction], compute_units: u32| {
+            let mut candidate = prefix.to_vec();
+            candidate.push(ComputeBudgetInstruction::set_compute_unit_limit(
+                compute_units + group_units,
+            ));
+            candidate.extend_from_slice(instructions);
+            candidate.extend(group.iter().cloned());
+            compute_units + group_units <= MAX_TRANSACTION_COMPUTE_UNITS
+                && serialized_transaction_size(&candidate, fee_payer) <= PACKET_DATA_SIZE
+        };
+
+        if !fits(&instructions, compute_units) {
+            if recipients.is_empty() {
+                return Err(SolanaError::InvalidInput(format!(
+                    "Payout {index} does not fit into a single transaction"
+                )));
+            }
+
+            planned.push(finish(
+                std::mem::take(&mut instructions),
+                std::mem::take(&mut recipients),
+                compute_units,
+            ));
+            compute_units = COMPUTE_BUDGET_UNITS;
+
+            if !fits(&instructions, compute_units) {
+                return Err(SolanaError::InvalidInput(format!(
+                    "Payout {index} does not fit into a single transaction"
+                )));
+            }
+        }
+
+        instructions.extend(group);
+        recipients.push(index);
+        compute_units += group_units;
+    }
+
+    if !recipients.is_empty() {
+        planned.push(finish(instructions, recipients, compute_units));
+    }
+
+    Ok(planned)
+}
+
+pub fn encode_transaction(
+    instructions: &[Instruction],
+    fee_payer: &Pubkey,
+    blockhash: Hash,
+) -> Result<(String, usize), SolanaError> {
+    let mut message = Message::new(instructions, Some(fee_payer));
+    message.recent_blockhash = blockhash;
+
+    let bytes = bincode::serialize(&Transaction::new_unsigned(message))
+        .map_err(|e| SolanaError::InvalidInput(format!("Failed to serialize transaction: {e}")))?;
+
+    Ok((general_purpose::STANDARD.encode(&bytes), bytes.len()))
+}
+
+// Builds a SOL transfer with an optional memo; `references` are appended as
+// read-only accounts so the payment can be found later (Solana Pay)
+pub fn build_sol_transfer(
+    from: &Pubkey,
+    to: &Pubkey,
+    lamports: u64,
+    memo: Option<&String>,
+    memo_signers: Option<&Vec<String>>,
+    references: &[Pubkey],
+) -> Result<TransferInstructions, SolanaError> {
+    // Validate that sender and recipient are different
+    if from == to {
+        return Err(SolanaError::InvalidInput(
+            "Sender and recipient cannot be the same".to_string(),
+        ));
+    }
+
+    info!(
+        "Creating SOL transfer: {} lamports ({} SOL) from {} to {}",
+        lamports,
+        lamports as f64 / 1_000_000_000.0,
+        from,
+        to
+    );
+
+    let memo_instruction = memo
+        .filter(|s| !s.trim().is_empty())
+        .map(|memo| build_memo_instruction(memo, memo_signers))
+        .transpose()?;
+
+    // Create transfer instruction
+    let mut instruction = system_instruction::transfer(from, to, lamports);
+    instruction.accounts.extend(
+        references
+            .iter()
+            .map(|reference| AccountMeta::new_readonly(*reference, false)),
+    );
+
+    Ok(TransferInstructions {
+        memo: memo_instruction,
+        transfer: instruction,
+        amount: lamports,
+        decimals: SOL_DECIMALS,
+    })
+}
+
+// Builds an SPL Token or Token-2022 transfer between the owner's and destination's ATAs
+pub fn build_token_transfer(
+    client: &RpcClient,
+    params: TokenTransferParams,
+) -> Result<TransferInstructions, SolanaError> {
+    let TokenTransferParams {
+        owner,
+        destination,
+        mint,
+        mint_state,
+        amount,
+        multisig_signers,
+        memo,
+        memo_signers,
+        references,
+        checked,
+    } = params;
+
+    // Validate that owner and destination are different
+    if owner == destination {
+        return Err(SolanaError::InvalidInput(
+            "Token owner and destination cannot be the same".to_string(),
+        ));
+    }
+
+    let program_id = mint_state.program_id;
+    let decimals = mint_state.base.decimals;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    // Derive Associated Token Accounts for both owner and destination
+    let source_ata = get_associated_token_address_with_program_id(&owner, &mint, &program_id);
+    let destination_ata =
+        get_associated_token_address_with_program_id(&destination, &mint, &program_id);
+
+    info!(
+        "Creating token transfer: {} tokens of mint {} from owner {} (ATA: {}) to destination {} (ATA: {})",
+        amount, mint, owner, source_ata, destination, destination_ata
+    );
+
+    let memo_instruction = memo
+        .filter(|s| !s.trim().is_empty())
+        .map(|memo| build_memo_instruction(memo, memo_signers))
+        .transpose()?;
+
+    // Token-2022 accounts with the MemoTransfer extension reject memo-less transfers
+    if program_id == spl_token_2022::id() && memo_instruction.is_none() {
+        if let Ok(destination_account) = fetch_token_account(client, &destination_ata) {
+            let requires_memo = destination_account.extensions.iter().any(|extension| {
+                matches!(
+                    extension,
+                    UiExtension::MemoTransfer(memo_transfer)
+                        if memo_transfer.require_incoming_transfer_memos
+                )
+            });
+
+            if requires_memo {
+                return Err(SolanaError::InvalidInput(
+                    "Destination account requires a memo on incoming transfers".to_string(),
+                ));
+            }
+        }
+    }
+
+    // Create transfer instruction using derived ATAs
+    let mut instruction = if checked || program_id == spl_token_2022::id() {
+        spl_token_2022::instruction::transfer_checked(
+            &program_id,
+            &source_ata,
+            &mint,
+            &destination_ata,
+            &owner,
+            &signer_refs,
+            amount,
+            decimals,
+        )
+    } else {
+        transfer(
+            &spl_token::id(),
+            &source_ata,
+            &destination_ata,
+            &owner,
+            &signer_refs,
+            amount,
+        )
+    }
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    instruction.accounts.extend(
+        references
+            .iter()
+            .map(|reference| AccountMeta::new_readonly(*reference, false)),
+    );
+
+    Ok(TransferInstructions {
+        memo: memo_instruction,
+        transfer: instruction,
+        amount,
+        decimals,
+    })
+}
+
+pub fn sol_transfer_response(transfer: &TransferInstructions) -> SendSolResponse {
+    let instruction = &transfer.transfer;
+
+    SendSolResponse {
+        program_id: instruction.program_id.to_string(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|acc| acc.pubkey.to_string())
+            .collect(),
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+        memo_instruction: transfer.memo.as_ref().map(instruction_response),
+    }
+}
+
+pub fn token_transfer_response(transfer: &TransferInstructions) -> SendTokenResponse {
+    let instruction = &transfer.transfer;
+
+    let accounts: Vec<AccountMetaTokenResponse> = instruction
+        .accounts
+        .iter()
+        .map(|acc| AccountMetaTokenResponse {
+            pubkey: acc.pubkey.to_string(),
+            is_signer: acc.is_signer,
+        })
+        .collect();
+
+    SendTokenResponse {
+        program_id: instruction.program_id.to_string(),
+        accounts,
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+        amount: transfer.amount,
+        ui_amount: format_ui_amount(transfer.amount, transfer.decimals),
+        decimals: transfer.decimals,
+        memo_instruction: transfer.memo.as_ref().map(instruction_response),
+    }
 }
 
 async fn send_sol(
+    headers: HeaderMap,
     Json(payload): Json<SendSolRequest>,
 ) -> Result<Json<serde_json::Value>, SolanaError> {
     info!(
@@ -75,22 +552,6 @@ async fn send_sol(
         .filter(|&l| l > 0)
         .ok_or(SolanaError::MissingFields)?;
 
-    // Validate lamports amount (reasonable limits)
-    const MIN_LAMPORTS: u64 = 1; // Minimum 1 lamport
-    const MAX_LAMPORTS: u64 = 100_000_000_000; // Maximum 100 SOL (100 * 10^9 lamports)
-
-    if lamports < MIN_LAMPORTS {
-        return Err(SolanaError::InvalidInput(
-            "Amount must be at least 1 lamport".to_string(),
-        ));
-    }
-
-    if lamports > MAX_LAMPORTS {
-        return Err(SolanaError::InvalidInput(
-            "Amount exceeds maximum limit (100 SOL)".to_string(),
-        ));
-    }
-
     // Parse public keys AFTER validation
     let from_pubkey = from
         .parse::<Pubkey>()
@@ -100,33 +561,27 @@ async fn send_sol(
         .parse::<Pubkey>()
         .map_err(|_| SolanaError::InvalidInput("Invalid recipient address".to_string()))?;
 
-    // Validate that sender and recipient are different
-    if from_pubkey == to_pubkey {
-        return Err(SolanaError::InvalidInput(
-            "Sender and recipient cannot be the same".to_string(),
-        ));
-    }
-
-    info!(
-        "Creating SOL transfer: {} lamports ({} SOL) from {} to {}",
+    let transfer = build_sol_transfer(
+        &from_pubkey,
+        &to_pubkey,
         lamports,
-        lamports as f64 / 1_000_000_000.0,
-        from_pubkey,
-        to_pubkey
-    );
+        payload.memo.as_ref(),
+        payload.memo_signers.as_ref(),
+        &[],
+    )?;
 
-    // Create transfer instruction
-    let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, lamports);
+    // Amount limits, recipient lists and velocity come from the policy config
+    policy().enforce(
+        "/send/sol",
+        api_key(&headers),
+        &[Transfer {
+            recipient: to_pubkey,
+            mint: None,
+            amount: lamports,
+        }],
+    )?;
 
-    let response = SendSolResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts: instruction
-            .accounts
-            .iter()
-            .map(|acc| acc.pubkey.to_string())
-            .collect(),
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
-    };
+    let response = sol_transfer_response(&transfer);
 
     let json_response = serde_json::json!({
         "success": true,
@@ -139,6 +594,7 @@ async fn send_sol(
 }
 
 async fn send_token(
+    headers: HeaderMap,
     Json(payload): Json<SendTokenRequest>,
 ) -> Result<Json<serde_json::Value>, SolanaError> {
     info!(
@@ -165,16 +621,8 @@ async fn send_token(
         .filter(|s| !s.trim().is_empty())
         .ok_or(SolanaError::MissingFields)?;
 
-    let amount = payload
-        .amount
-        .filter(|&a| a > 0)
-        .ok_or(SolanaError::MissingFields)?;
-
-    // Validate amount range (prevent overflow and ensure reasonable limits)
-    if amount > u64::MAX / 2 {
-        return Err(SolanaError::InvalidInput(
-            "Token amount is too large".to_string(),
-        ));
+    if payload.amount.is_none() && payload.ui_amount.is_none() {
+        return Err(SolanaError::MissingFields);
     }
 
     // Parse public keys AFTER validation
@@ -190,46 +638,221 @@ async fn send_token(
         .parse::<Pubkey>()
         .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
 
-    // Validate that owner and destination are different
-    if owner_pubkey == destination_pubkey {
-        return Err(SolanaError::InvalidInput(
-            "Token owner and destination cannot be the same".to_string(),
-        ));
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+
+    // The mint determines the token program, and so the ATAs and decimals
+    let client = get_rpc_client();
+    let mint_state = fetch_mint(&client, &mint_pubkey)?;
+    let decimals = mint_state.base.decimals;
+
+    if payload.decimals.is_some_and(|d| d != decimals) {
+        return Err(SolanaError::InvalidInput(format!(
+            "Decimals do not match the mint ({decimals})"
+        )));
     }
 
-    // Derive Associated Token Accounts for both owner and destination
-    let source_ata = get_associated_token_address(&owner_pubkey, &mint_pubkey);
-    let destination_ata = get_associated_token_address(&destination_pubkey, &mint_pubkey);
+    let amount = resolve_amount(payload.amount, payload.ui_amount.as_ref(), decimals)?;
+
+    let transfer = build_token_transfer(
+        &client,
+        TokenTransferParams {
+            owner: owner_pubkey,
+            destination: destination_pubkey,
+            mint: mint_pubkey,
+            mint_state: &mint_state,
+            amount,
+            multisig_signers: &multisig_signers,
+            memo: payload.memo.as_ref(),
+            memo_signers: payload.memo_signers.as_ref(),
+            references: &[],
+            checked: false,
+        },
+    )?;
+
+    // Amount limits, recipient lists and velocity come from the policy config
+    policy().enforce(
+        "/send/token",
+        api_key(&headers),
+        &[Transfer {
+            recipient: destination_pubkey,
+            mint: Some(mint_pubkey),
+            amount,
+        }],
+    )?;
+
+    let response = token_transfer_response(&transfer);
 
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Token transfer instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn send_batch(
+    headers: HeaderMap,
+    Json(payload): Json<BatchPayoutRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
     info!(
-        "Creating token transfer: {} tokens of mint {} from owner {} (ATA: {}) to destination {} (ATA: {})",
-        amount, mint_pubkey, owner_pubkey, source_ata, destination_pubkey, destination_ata
+        "POST /send/batch - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
     );
 
-    // Create transfer instruction using derived ATAs
-    let instruction = transfer(
-        &spl_token::id(),
-        &source_ata,
-        &destination_ata,
-        &owner_pubkey,
-        &[],
-        amount,
-    )
-    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+    // Validate required fields are present and not empty
+    let fee_payer = payload
+        .fee_payer
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
 
-    let accounts: Vec<AccountMetaTokenResponse> = instruction
-        .accounts
+    let items = payload
+        .payouts
+        .as_ref()
+        .filter(|p| !p.is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    if items.len() > MAX_BATCH_PAYOUTS {
+        return Err(SolanaError::InvalidInput(format!(
+            "At most {MAX_BATCH_PAYOUTS} payouts are allowed per batch"
+        )));
+    }
+
+    // Parse public keys AFTER validation
+    let fee_payer_pubkey = fee_payer
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid fee payer address".to_string()))?;
+
+    let sender_pubkey = match payload.sender.as_ref().filter(|s| !s.trim().is_empty()) {
+        Some(sender) => sender
+            .parse::<Pubkey>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid sender address".to_string()))?,
+        None => fee_payer_pubkey,
+    };
+
+    let client = get_rpc_client();
+
+    // Fetch every distinct mint once for its program and decimals
+    let mut mints: HashMap<Pubkey, TokenState<Mint>> = HashMap::new();
+    let mut payouts = Vec::with_capacity(items.len());
+
+    for (index, item) in items.iter().enumerate() {
+        let recipient = item
+            .recipient
+            .as_ref()
+            .filter(|s| !s.trim().is_empty())
+            .ok_or(SolanaError::MissingFields)?;
+
+        let recipient_pubkey = recipient.parse::<Pubkey>().map_err(|_| {
+            SolanaError::InvalidInput(format!("Invalid recipient address at index {index}"))
+        })?;
+
+        if recipient_pubkey == sender_pubkey {
+            return Err(SolanaError::InvalidInput(format!(
+                "Sender and recipient cannot be the same (index {index})"
+            )));
+        }
+
+        let mint_pubkey = item
+            .mint
+            .as_ref()
+            .filter(|s| !s.trim().is_empty())
+            .map(|mint| {
+                mint.parse::<Pubkey>().map_err(|_| {
+                    SolanaError::InvalidInput(format!("Invalid mint address at index {index}"))
+                })
+            })
+            .transpose()?;
+
+        let decimals = match mint_pubkey {
+            Some(mint_pubkey) => {
+                let mint = match mints.entry(mint_pubkey) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(fetch_mint(&client, &mint_pubkey)?),
+                };
+                mint.base.decimals
+            }
+            None => SOL_DECIMALS,
+        };
+
+        let amount = resolve_amount(item.amount, item.ui_amount.as_ref(), decimals)?;
+
+        payouts.push(Payout {
+            recipient: recipient_pubkey,
+            amount,
+            mint: mint_pubkey,
+        });
+    }
+
+    let transfers: Vec<Transfer> = payouts
         .iter()
-        .map(|acc| AccountMetaTokenResponse {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
+        .map(|payout| Transfer {
+            recipient: payout.recipient,
+            mint: payout.mint,
+            amount: payout.amount,
         })
         .collect();
 
-    let response = SendTokenResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let durable_nonce = payload
+        .use_nonce
+        .as_ref()
+        .map(|use_nonce| resolve_durable_nonce(&client, use_nonce, &fee_payer_pubkey))
+        .transpose()?;
+
+    // advance_nonce_account must be the first instruction of a durable transaction
+    let prefix: Vec<Instruction> = durable_nonce
+        .iter()
+        .map(|nonce| nonce.advance_instruction.clone())
+        .collect();
+
+    let planned = plan_batch(&payouts, &fee_payer_pubkey, &sender_pubkey, &mints, &prefix)?;
+
+    // A nonce value is consumed by the first transaction that uses it
+    if durable_nonce.is_some() && planned.len() > 1 {
+        return Err(SolanaError::InvalidInput(format!(
+            "Payouts need {} transactions but a durable nonce covers only one",
+            planned.len()
+        )));
+    }
+
+    let recent_blockhash = match &durable_nonce {
+        Some(nonce) => nonce.nonce,
+        None => client.get_latest_blockhash()?,
+    };
+
+    let transactions = planned
+        .iter()
+        .map(|planned| {
+            let (transaction, size) =
+                encode_transaction(&planned.instructions, &fee_payer_pubkey, recent_blockhash)?;
+            Ok(BatchTransactionResponse {
+                transaction,
+                recipients: planned.recipients.clone(),
+                instruction_count: planned.instructions.len(),
+                size,
+                compute_units: planned.compute_units,
+            })
+        })
+        .collect::<Result<Vec<_>, SolanaError>>()?;
+
+    // Checked last so velocity is only consumed for batches that are returned
+    policy().enforce("/send/batch", api_key(&headers), &transfers)?;
+
+    info!(
+        "Packed {} payouts into {} transactions for fee payer {}",
+        payouts.len(),
+        transactions.len(),
+        fee_payer_pubkey
+    );
+
+    let response = BatchPayoutResponse {
+        fee_payer: fee_payer_pubkey.to_string(),
+        sender: sender_pubkey.to_string(),
+        recent_blockhash: recent_blockhash.to_string(),
+        nonce_account: durable_nonce.map(|nonce| nonce.nonce_account.to_string()),
+        transactions,
     };
 
     let json_response = serde_json::json!({
@@ -237,7 +860,198 @@ async fn send_token(
         "data": response
     });
 
-    info!("Response: 200 - Token transfer instruction created successfully");
+    info!("Response: 200 - Batch payout transactions created successfully");
 
     Ok(Json(json_response))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::compute_budget;
+
+    fn sol_payouts(count: usize) -> Vec<Payout> {
+        (0..count)
+            .map(|index| Payout {
+                recipient: Pubkey::new_unique(),
+                amount: index as u64 + 1,
+                mint: None,
+            })
+            .collect()
+    }
+
+    fn transaction_size(planned: &PlannedTransaction, fee_payer: &Pubkey) -> usize {
+        serialized_transaction_size(&planned.instructions, fee_payer)
+    }
+
+    #[test]
+    fn plan_batch_without_payouts_is_empty() {
+        let fee_payer = Pubkey::new_unique();
+
+        let planned = plan_batch(&[], &fee_payer, &fee_payer, &HashMap::new(), &[]).unwrap();
+
+        assert!(planned.is_empty());
+    }
+
+    #[test]
+    fn plan_batch_packs_small_batches_into_one_transaction() {
+        let fee_payer = Pubkey::new_unique();
+        let payouts = sol_payouts(3);
+
+        let planned = plan_batch(&payouts, &fee_payer, &fee_payer, &HashMap::new(), &[]).unwrap();
+
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].recipients, vec![0, 1, 2]);
+        assert_eq!(
+            planned[0].compute_units,
+            COMPUTE_BUDGET_UNITS + 3 * SOL_TRANSFER_UNITS
+        );
+        // The compute budget comes first, then one transfer per payout in order
+        assert_eq!(planned[0].instructions.len(), 4);
+        assert_eq!(planned[0].instructions[0].program_id, compute_budget::id());
+        for (instruction, payout) in planned[0].instructions[1..].iter().zip(&payouts) {
+            assert_eq!(instruction.accounts[1].pubkey, payout.recipient);
+        }
+    }
+
+    #[test]
+    fn plan_batch_splits_at_the_packet_size() {
+        let fee_payer = Pubkey::new_unique();
+        let payouts = sol_payouts(60);
+
+        let planned = plan_batch(&payouts, &fee_payer, &fee_payer, &HashMap::new(), &[]).unwrap();
+
+        assert!(planned.len() > 1);
+        let recipients: Vec<usize> = planned
+            .iter()
+            .flat_map(|planned| planned.recipients.clone())
+            .collect();
+        assert_eq!(recipients, (0..60).collect::<Vec<_>>());
+
+        for (index, transaction) in planned.iter().enumerate() {
+            assert!(transaction_size(transaction, &fee_payer) <= PACKET_DATA_SIZE);
+            assert_eq!(
+                transaction.compute_units,
+                COMPUTE_BUDGET_UNITS + transaction.recipients.len() as u32 * SOL_TRANSFER_UNITS
+            );
+
+            // Greedy packing: the next payout would not have fit
+            if let Some(next) = planned.get(index + 1) {
+                let mut instructions = transaction.instructions.clone();
+                instructions.push(next.instructions[1].clone());
+                assert!(serialized_transaction_size(&instructions, &fee_payer) > PACKET_DATA_SIZE);
+            }
+        }
+    }
+
+    #[test]
+    fn plan_batch_repeats_the_prefix_in_every_transaction() {
+        let fee_payer = Pubkey::new_unique();
+        let prefix = Instruction::new_with_bytes(spl_memo::id(), b"prefix", vec![]);
+
+        let planned = plan_batch(
+            &sol_payouts(60),
+            &fee_payer,
+            &fee_payer,
+            &HashMap::new(),
+            std::slice::from_ref(&prefix),
+        )
+        .unwrap();
+
+        for transaction in &planned {
+            assert_eq!(transaction.instructions[0], prefix);
+            assert_eq!(transaction.instructions[1].program_id, compute_budget::id());
+            assert!(transaction_size(transaction, &fee_payer) <= PACKET_DATA_SIZE);
+        }
+    }
+
+    #[test]
+    fn plan_batch_creates_recipient_token_accounts() {
+        let fee_payer = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mints = HashMap::from([(
+            mint,
+            TokenState {
+                program_id: spl_token_2022::id(),
+                base: Mint {
+                    decimals: 6,
+                    is_initialized: true,
+                    ..Default::default()
+                },
+                extensions: Vec::new(),
+            },
+        )]);
+        let payout = Payout {
+            recipient: Pubkey::new_unique(),
+            amount: 5,
+            mint: Some(mint),
+        };
+
+        let planned = plan_batch(
+            std::slice::from_ref(&payout),
+            &fee_payer,
+            &sender,
+            &mints,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(planned.len(), 1);
+        assert_eq!(
+            planned[0].compute_units,
+            COMPUTE_BUDGET_UNITS + CREATE_ATA_UNITS + TOKEN_TRANSFER_UNITS
+        );
+
+        let [_, create_ata, transfer] = planned[0].instructions.as_slice() else {
+            panic!("expected a compute budget, an ATA creation and a transfer");
+        };
+        assert_eq!(create_ata.program_id, spl_associated_token_account::id());
+        assert_eq!(transfer.program_id, spl_token_2022::id());
+        assert_eq!(
+            transfer.accounts[2].pubkey,
+            get_associated_token_address_with_program_id(
+                &payout.recipient,
+                &mint,
+                &spl_token_2022::id()
+            )
+        );
+    }
+
+    #[test]
+    fn plan_batch_rejects_unknown_mints() {
+        let fee_payer = Pubkey::new_unique();
+        let payout = Payout {
+            recipient: Pubkey::new_unique(),
+            amount: 5,
+            mint: Some(Pubkey::new_unique()),
+        };
+
+        let result = plan_batch(&[payout], &fee_payer, &fee_payer, &HashMap::new(), &[]);
+
+        assert!(matches!(
+            result,
+            Err(SolanaError::InvalidInput(message)) if message.starts_with("Unknown mint")
+        ));
+    }
+
+    #[test]
+    fn plan_batch_rejects_payouts_that_never_fit() {
+        let fee_payer = Pubkey::new_unique();
+        let prefix = Instruction::new_with_bytes(spl_memo::id(), &[b'x'; 1_150], vec![]);
+
+        let result = plan_batch(
+            &sol_payouts(1),
+            &fee_payer,
+            &fee_payer,
+            &HashMap::new(),
+            &[prefix],
+        );
+
+        assert!(matches!(
+            result,
+            Err(SolanaError::InvalidInput(message))
+                if message == "Payout 0 does not fit into a single transaction"
+        ));
+    }
+}
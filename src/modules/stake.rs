@@ -0,0 +1,782 @@
+use crate::modules::keypair::KeypairResponse;
+use crate::modules::token::{instruction_response, InstructionResponse};
+use crate::utils::amount::{format_ui_amount, SOL_DECIMALS};
+use crate::utils::cache::TtlCache;
+use crate::utils::errors::SolanaError;
+use crate::utils::pubkey::{parse_optional_pubkey, parse_pubkey};
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    feature,
+    feature_set::reduce_stake_warmup_cooldown,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    stake::{
+        self, instruction as stake_instruction,
+        state::{Authorized, Lockup, Meta, StakeAuthorize, StakeStateV2},
+    },
+    stake_history::StakeHistory,
+    system_instruction, sysvar,
+};
+use std::sync::Arc;
+use tracing::info;
+
+const EPOCH_CONTEXT_KEY: &str = "epoch_context";
+
+#[derive(Deserialize, Serialize)]
+pub struct LockupRequest {
+    #[serde(rename = "unixTimestamp")]
+    pub unix_timestamp: Option<i64>,
+    pub epoch: Option<u64>,
+    pub custodian: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateStakeRequest {
+    pub payer: Option<String>,
+    #[serde(rename = "voteAccount")]
+    pub vote_account: Option<String>,
+    pub lamports: Option<u64>,
+    // Generated server-side when neither this nor `seed` is provided
+    #[serde(rename = "stakeAccount")]
+    pub stake_account: Option<String>,
+    // Derives the stake account from the payer with create_with_seed
+    pub seed: Option<String>,
+    // Both authorities default to the payer
+    pub staker: Option<String>,
+    pub withdrawer: Option<String>,
+    pub lockup: Option<LockupRequest>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct DeactivateStakeRequest {
+    #[serde(rename = "stakeAccount")]
+    pub stake_account: Option<String>,
+    pub staker: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct WithdrawStakeRequest {
+    #[serde(rename = "stakeAccount")]
+    pub stake_account: Option<String>,
+    pub withdrawer: Option<String>,
+    pub destination: Option<String>,
+    pub lamports: Option<u64>,
+    // Required while a lockup is in force
+    pub custodian: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SplitStakeRequest {
+    #[serde(rename = "stakeAccount")]
+    pub stake_account: Option<String>,
+    pub staker: Option<String>,
+    pub lamports: Option<u64>,
+    // Generated server-side when neither this nor `seed` is provided
+    #[serde(rename = "splitStakeAccount")]
+    pub split_stake_account: Option<String>,
+    // Derives the new account from the staker with create_with_seed
+    pub seed: Option<String>,
+    // Funds the rent-exempt reserve of the new account when provided
+    pub payer: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MergeStakeRequest {
+    #[serde(rename = "destinationStakeAccount")]
+    pub destination_stake_account: Option<String>,
+    #[serde(rename = "sourceStakeAccount")]
+    pub source_stake_account: Option<String>,
+    pub staker: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AuthorizeStakeRequest {
+    #[serde(rename = "stakeAccount")]
+    pub stake_account: Option<String>,
+    pub authority: Option<String>,
+    #[serde(rename = "newAuthority")]
+    pub new_authority: Option<String>,
+    // "staker" or "withdrawer"
+    #[serde(rename = "authorityType")]
+    pub authority_type: Option<String>,
+    pub custodian: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CreateStakeResponse {
+    pub stake_account: String,
+    pub vote_account: String,
+    pub staker: String,
+    pub withdrawer: String,
+    pub lamports: u64,
+    pub rent_exempt_reserve: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    pub instructions: Vec<InstructionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stake_keypair: Option<KeypairResponse>,
+}
+
+#[derive(Serialize)]
+pub struct StakeInstructionResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub stake_account: String,
+}
+
+#[derive(Serialize)]
+pub struct SplitStakeResponse {
+    pub stake_account: String,
+    pub split_stake_account: String,
+    pub lamports: u64,
+    pub instructions: Vec<InstructionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_stake_keypair: Option<KeypairResponse>,
+}
+
+#[derive(Serialize)]
+pub struct MergeStakeResponse {
+    pub destination_stake_account: String,
+    pub source_stake_account: String,
+    pub instructions: Vec<InstructionResponse>,
+}
+
+#[derive(Serialize)]
+pub struct StakeAuthoritiesResponse {
+    pub staker: String,
+    pub withdrawer: String,
+}
+
+#[derive(Serialize)]
+pub struct LockupResponse {
+    pub unix_timestamp: i64,
+    pub epoch: u64,
+    pub custodian: String,
+}
+
+#[derive(Serialize)]
+pub struct DelegationResponse {
+    pub voter: String,
+    pub stake: u64,
+    pub activation_epoch: u64,
+    // None while the stake has not been deactivated
+    pub deactivation_epoch: Option<u64>,
+    pub credits_observed: u64,
+}
+
+#[derive(Serialize)]
+pub struct StakeAccountResponse {
+    pub address: String,
+    pub lamports: u64,
+    pub sol: String,
+    // uninitialized, initialized, delegated or rewards_pool
+    pub state: String,
+    pub rent_exempt_reserve: Option<u64>,
+    pub authorities: Option<StakeAuthoritiesResponse>,
+    pub lockup: Option<LockupResponse>,
+    pub delegation: Option<DelegationResponse>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct StakeActivationResponse {
+    pub address: String,
+    pub epoch: u64,
+    // active, inactive, activating or deactivating
+    pub state: String,
+    pub delegated_stake: u64,
+    pub active: u64,
+    pub inactive: u64,
+    pub activating: u64,
+    pub deactivating: u64,
+}
+
+// Everything needed to compute activation for the current epoch
+#[derive(Clone)]
+struct EpochContext {
+    epoch: u64,
+    stake_history: Arc<StakeHistory>,
+    new_rate_activation_epoch: Option<u64>,
+}
+
+#[derive(Clone)]
+pub struct StakeState {
+    epoch_context: TtlCache<EpochContext>,
+    activations: TtlCache<StakeActivationResponse>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/stake/create", post(create_stake))
+        .route("/stake/deactivate", post(deactivate_stake))
+        .route("/stake/withdraw", post(withdraw_stake))
+        .route("/stake/split", post(split_stake))
+        .route("/stake/merge", post(merge_stake))
+        .route("/stake/authorize", post(authorize_stake))
+        .route("/stake/:address", get(get_stake))
+        .route("/stake/:address/activation", get(get_stake_activation))
+        .with_state(StakeState {
+            epoch_context: TtlCache::from_env(),
+            activations: TtlCache::from_env(),
+        })
+}
+
+fn keypair_response(keypair: &Keypair) -> KeypairResponse {
+    KeypairResponse {
+        pubkey: keypair.pubkey().to_string(),
+        secret: bs58::encode(&keypair.to_bytes()).into_string(),
+    }
+}
+
+// Resolves a new stake account from an explicit address, a seed on `base`, or a fresh keypair
+fn new_stake_account(
+    address: Option<&String>,
+    seed: Option<&String>,
+    base: &Pubkey,
+    name: &str,
+) -> Result<(Pubkey, Option<String>, Option<Keypair>), SolanaError> {
+    let seed = seed.filter(|s| !s.is_empty());
+    let address = parse_optional_pubkey(address, name)?;
+
+    match (address, seed) {
+        (Some(_), Some(_)) => Err(SolanaError::InvalidInput(format!(
+            "Provide either {name} or seed, not both"
+        ))),
+        (Some(address), None) => Ok((address, None, None)),
+        (None, Some(seed)) => {
+            let address = Pubkey::create_with_seed(base, seed, &stake::program::id())
+                .map_err(|e| SolanaError::InvalidInput(format!("Invalid seed: {e}")))?;
+            Ok((address, Some(seed.clone()), None))
+        }
+        (None, None) => {
+            let keypair = Keypair::new();
+            Ok((keypair.pubkey(), None, Some(keypair)))
+        }
+    }
+}
+
+// Reads and decodes a stake account
+pub fn fetch_stake(
+    client: &RpcClient,
+    stake_account: &Pubkey,
+) -> Result<(u64, StakeStateV2), SolanaError> {
+    let account = client
+        .get_account_with_commitment(stake_account, client.commitment())?
+        .value
+        .ok_or_else(|| SolanaError::InvalidInput("Stake account does not exist".to_string()))?;
+
+    if account.owner != stake::program::id() {
+        return Err(SolanaError::InvalidInput(
+            "Account is not a stake account".to_string(),
+        ));
+    }
+
+    let state = bincode::deserialize::<StakeStateV2>(&account.data)
+        .map_err(|e| SolanaError::InvalidInput(format!("Invalid stake account data: {e}")))?;
+
+    Ok((account.lamports, state))
+}
+
+async fn create_stake(
+    Json(payload): Json<CreateStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/create - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let lamports = payload
+        .lamports
+        .filter(|&l| l > 0)
+        .ok_or(SolanaError::MissingFields)?;
+
+    // Parse public keys AFTER validation
+    let payer_pubkey = parse_pubkey(payload.payer.as_ref(), "payer")?;
+    let vote_pubkey = parse_pubkey(payload.vote_account.as_ref(), "vote account")?;
+
+    let authorized = Authorized {
+        staker: parse_optional_pubkey(payload.staker.as_ref(), "staker")?.unwrap_or(payer_pubkey),
+        withdrawer: parse_optional_pubkey(payload.withdrawer.as_ref(), "withdrawer")?
+            .unwrap_or(payer_pubkey),
+    };
+
+    let lockup = match &payload.lockup {
+        Some(lockup) => Lockup {
+            unix_timestamp: lockup.unix_timestamp.unwrap_or_default(),
+            epoch: lockup.epoch.unwrap_or_default(),
+            custodian: parse_optional_pubkey(lockup.custodian.as_ref(), "custodian")?
+                .unwrap_or_default(),
+        },
+        None => Lockup::default(),
+    };
+
+    let (stake_pubkey, seed, stake_keypair) = new_stake_account(
+        payload.stake_account.as_ref(),
+        payload.seed.as_ref(),
+        &payer_pubkey,
+        "stake account",
+    )?;
+
+    if stake_pubkey == payer_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Payer and stake account cannot be the same".to_string(),
+        ));
+    }
+
+    // The account must hold its rent-exempt reserve plus the minimum delegation
+    let client = get_rpc_client();
+    let rent_exempt_reserve =
+        client.get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())?;
+    let minimum_delegation = client.get_stake_minimum_delegation()?;
+    let required = rent_exempt_reserve.saturating_add(minimum_delegation);
+
+    if lamports < required {
+        return Err(SolanaError::InvalidInput(format!(
+            "Stake account needs at least {required} lamports ({rent_exempt_reserve} rent-exempt reserve + {minimum_delegation} minimum delegation)"
+        )));
+    }
+
+    info!(
+        "Creating stake account {} with {} lamports delegated to {}",
+        stake_pubkey, lamports, vote_pubkey
+    );
+
+    let instructions = match &seed {
+        Some(seed) => stake_instruction::create_account_with_seed_and_delegate_stake(
+            &payer_pubkey,
+            &stake_pubkey,
+            &payer_pubkey,
+            seed,
+            &vote_pubkey,
+            &authorized,
+            &lockup,
+            lamports,
+        ),
+        None => stake_instruction::create_account_and_delegate_stake(
+            &payer_pubkey,
+            &stake_pubkey,
+            &vote_pubkey,
+            &authorized,
+            &lockup,
+            lamports,
+        ),
+    };
+
+    let response = CreateStakeResponse {
+        stake_account: stake_pubkey.to_string(),
+        vote_account: vote_pubkey.to_string(),
+        staker: authorized.staker.to_string(),
+        withdrawer: authorized.withdrawer.to_string(),
+        lamports,
+        rent_exempt_reserve,
+        seed,
+        instructions: instructions.iter().map(instruction_response).collect(),
+        stake_keypair: stake_keypair.as_ref().map(keypair_response),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Stake account creation instructions generated successfully");
+
+    Ok(Json(json_response))
+}
+
+fn stake_instruction_json(
+    instruction: &Instruction,
+    stake_pubkey: &Pubkey,
+) -> Json<serde_json::Value> {
+    let response = StakeInstructionResponse {
+        instruction: instruction_response(instruction),
+        stake_account: stake_pubkey.to_string(),
+    };
+
+    Json(serde_json::json!({
+        "success": true,
+        "data": response
+    }))
+}
+
+async fn deactivate_stake(
+    Json(payload): Json<DeactivateStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/deactivate - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let stake_pubkey = parse_pubkey(payload.stake_account.as_ref(), "stake account")?;
+    let staker_pubkey = parse_pubkey(payload.staker.as_ref(), "staker")?;
+
+    let instruction = stake_instruction::deactivate_stake(&stake_pubkey, &staker_pubkey);
+
+    info!("Response: 200 - Stake deactivate instruction created successfully");
+
+    Ok(stake_instruction_json(&instruction, &stake_pubkey))
+}
+
+async fn withdraw_stake(
+    Json(payload): Json<WithdrawStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/withdraw - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let lamports = payload
+        .lamports
+        .filter(|&l| l > 0)
+        .ok_or(SolanaError::MissingFields)?;
+
+    let stake_pubkey = parse_pubkey(payload.stake_account.as_ref(), "stake account")?;
+    let withdrawer_pubkey = parse_pubkey(payload.withdrawer.as_ref(), "withdrawer")?;
+    let destination_pubkey = parse_pubkey(payload.destination.as_ref(), "destination")?;
+    let custodian_pubkey = parse_optional_pubkey(payload.custodian.as_ref(), "custodian")?;
+
+    let instruction = stake_instruction::withdraw(
+        &stake_pubkey,
+        &withdrawer_pubkey,
+        &destination_pubkey,
+        lamports,
+        custodian_pubkey.as_ref(),
+    );
+
+    info!("Response: 200 - Stake withdraw instruction created successfully");
+
+    Ok(stake_instruction_json(&instruction, &stake_pubkey))
+}
+
+async fn split_stake(
+    Json(payload): Json<SplitStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/split - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let lamports = payload
+        .lamports
+        .filter(|&l| l > 0)
+        .ok_or(SolanaError::MissingFields)?;
+
+    let stake_pubkey = parse_pubkey(payload.stake_account.as_ref(), "stake account")?;
+    let staker_pubkey = parse_pubkey(payload.staker.as_ref(), "staker")?;
+    let payer_pubkey = parse_optional_pubkey(payload.payer.as_ref(), "payer")?;
+
+    let (split_pubkey, seed, split_keypair) = new_stake_account(
+        payload.split_stake_account.as_ref(),
+        payload.seed.as_ref(),
+        &staker_pubkey,
+        "split stake account",
+    )?;
+
+    if split_pubkey == stake_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Split stake account must differ from the source".to_string(),
+        ));
+    }
+
+    let mut instructions = Vec::new();
+
+    // Split destinations must already hold their rent-exempt reserve
+    if let Some(payer_pubkey) = payer_pubkey {
+        let client = get_rpc_client();
+        let rent_exempt_reserve =
+            client.get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())?;
+        instructions.push(system_instruction::transfer(
+            &payer_pubkey,
+            &split_pubkey,
+            rent_exempt_reserve,
+        ));
+    }
+
+    instructions.extend(match &seed {
+        Some(seed) => stake_instruction::split_with_seed(
+            &stake_pubkey,
+            &staker_pubkey,
+            lamports,
+            &split_pubkey,
+            &staker_pubkey,
+            seed,
+        ),
+        None => stake_instruction::split(&stake_pubkey, &staker_pubkey, lamports, &split_pubkey),
+    });
+
+    let response = SplitStakeResponse {
+        stake_account: stake_pubkey.to_string(),
+        split_stake_account: split_pubkey.to_string(),
+        lamports,
+        instructions: instructions.iter().map(instruction_response).collect(),
+        split_stake_keypair: split_keypair.as_ref().map(keypair_response),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Stake split instructions created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn merge_stake(
+    Json(payload): Json<MergeStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/merge - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let destination_pubkey = parse_pubkey(
+        payload.destination_stake_account.as_ref(),
+        "destination stake account",
+    )?;
+    let source_pubkey = parse_pubkey(
+        payload.source_stake_account.as_ref(),
+        "source stake account",
+    )?;
+    let staker_pubkey = parse_pubkey(payload.staker.as_ref(), "staker")?;
+
+    if destination_pubkey == source_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Cannot merge a stake account into itself".to_string(),
+        ));
+    }
+
+    let instructions =
+        stake_instruction::merge(&destination_pubkey, &source_pubkey, &staker_pubkey);
+
+    let response = MergeStakeResponse {
+        destination_stake_account: destination_pubkey.to_string(),
+        source_stake_account: source_pubkey.to_string(),
+        instructions: instructions.iter().map(instruction_response).collect(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Stake merge instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn authorize_stake(
+    Json(payload): Json<AuthorizeStakeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /stake/authorize - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let authority_type = payload
+        .authority_type
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let stake_authorize = match authority_type.as_str() {
+        "staker" => StakeAuthorize::Staker,
+        "withdrawer" => StakeAuthorize::Withdrawer,
+        _ => {
+            return Err(SolanaError::InvalidInput(
+                "authorityType must be staker or withdrawer".to_string(),
+            ))
+        }
+    };
+
+    let stake_pubkey = parse_pubkey(payload.stake_account.as_ref(), "stake account")?;
+    let authority_pubkey = parse_pubkey(payload.authority.as_ref(), "authority")?;
+    let new_authority_pubkey = parse_pubkey(payload.new_authority.as_ref(), "new authority")?;
+    let custodian_pubkey = parse_optional_pubkey(payload.custodian.as_ref(), "custodian")?;
+
+    let instruction = stake_instruction::authorize(
+        &stake_pubkey,
+        &authority_pubkey,
+        &new_authority_pubkey,
+        stake_authorize,
+        custodian_pubkey.as_ref(),
+    );
+
+    info!("Response: 200 - Stake authorize instruction created successfully");
+
+    Ok(stake_instruction_json(&instruction, &stake_pubkey))
+}
+
+async fn get_stake(Path(address): Path<String>) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /stake/{}", address);
+
+    let stake_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid stake account address".to_string()))?;
+
+    let client = get_rpc_client();
+    let (lamports, state) = fetch_stake(&client, &stake_pubkey)?;
+
+    let (state_name, meta, stake) = match state {
+        StakeStateV2::Uninitialized => ("uninitialized", None, None),
+        StakeStateV2::Initialized(meta) => ("initialized", Some(meta), None),
+        StakeStateV2::Stake(meta, stake, _) => ("delegated", Some(meta), Some(stake)),
+        StakeStateV2::RewardsPool => ("rewards_pool", None, None),
+    };
+
+    let response = StakeAccountResponse {
+        address: stake_pubkey.to_string(),
+        lamports,
+        sol: format_ui_amount(lamports, SOL_DECIMALS),
+        state: state_name.to_string(),
+        rent_exempt_reserve: meta.map(|meta| meta.rent_exempt_reserve),
+        authorities: meta.map(|Meta { authorized, .. }| StakeAuthoritiesResponse {
+            staker: authorized.staker.to_string(),
+            withdrawer: authorized.withdrawer.to_string(),
+        }),
+        lockup: meta.map(|Meta { lockup, .. }| LockupResponse {
+            unix_timestamp: lockup.unix_timestamp,
+            epoch: lockup.epoch,
+            custodian: lockup.custodian.to_string(),
+        }),
+        delegation: stake.map(|stake| DelegationResponse {
+            voter: stake.delegation.voter_pubkey.to_string(),
+            stake: stake.delegation.stake,
+            activation_epoch: stake.delegation.activation_epoch,
+            deactivation_epoch: Some(stake.delegation.deactivation_epoch)
+                .filter(|&epoch| epoch != u64::MAX),
+            credits_observed: stake.credits_observed,
+        }),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Stake account fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+fn fetch_epoch_context(client: &RpcClient) -> Result<EpochContext, SolanaError> {
+    let epoch = client.get_epoch_info()?.epoch;
+
+    let history_account = client.get_account(&sysvar::stake_history::id())?;
+    let stake_history = bincode::deserialize::<StakeHistory>(&history_account.data)
+        .map_err(|e| SolanaError::InvalidInput(format!("Invalid stake history sysvar: {e}")))?;
+
+    // The warmup/cooldown rate drops once reduce_stake_warmup_cooldown is active
+    let activated_slot = client
+        .get_account_with_commitment(&reduce_stake_warmup_cooldown::id(), client.commitment())?
+        .value
+        .and_then(|account| feature::from_account(&account))
+        .and_then(|feature| feature.activated_at);
+
+    let new_rate_activation_epoch = match activated_slot {
+        Some(slot) => Some(client.get_epoch_schedule()?.get_epoch(slot)),
+        None => None,
+    };
+
+    Ok(EpochContext {
+        epoch,
+        stake_history: Arc::new(stake_history),
+        new_rate_activation_epoch,
+    })
+}
+
+fn compute_activation(
+    client: &RpcClient,
+    context: &EpochContext,
+    stake_pubkey: &Pubkey,
+) -> Result<StakeActivationResponse, SolanaError> {
+    let (lamports, state) = fetch_stake(client, stake_pubkey)?;
+
+    let (meta, status, delegated_stake) = match state {
+        StakeStateV2::Stake(meta, stake, _) => (
+            meta,
+            stake.delegation.stake_activating_and_deactivating(
+                context.epoch,
+                &context.stake_history,
+                context.new_rate_activation_epoch,
+            ),
+            stake.delegation.stake,
+        ),
+        StakeStateV2::Initialized(meta) => (meta, Default::default(), 0),
+        _ => {
+            return Err(SolanaError::InvalidInput(
+                "Stake account is not initialized".to_string(),
+            ))
+        }
+    };
+
+    // Same accounting as the getStakeActivation RPC method
+    let inactive = lamports
+        .saturating_sub(status.effective)
+        .saturating_sub(meta.rent_exempt_reserve);
+
+    let state_name = if status.deactivating > 0 {
+        "deactivating"
+    } else if status.activating > 0 {
+        "activating"
+    } else if status.effective > 0 {
+        "active"
+    } else {
+        "inactive"
+    };
+
+    Ok(StakeActivationResponse {
+        address: stake_pubkey.to_string(),
+        epoch: context.epoch,
+        state: state_name.to_string(),
+        delegated_stake,
+        active: status.effective,
+        inactive,
+        activating: status.activating,
+        deactivating: status.deactivating,
+    })
+}
+
+async fn get_stake_activation(
+    State(state): State<StakeState>,
+    Path(address): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /stake/{}/activation", address);
+
+    let stake_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid stake account address".to_string()))?;
+
+    let client = get_rpc_client();
+
+    let context = state
+        .epoch_context
+        .get_or_try_insert(EPOCH_CONTEXT_KEY, || fetch_epoch_context(&client))?;
+
+    // Keyed by epoch so a cached result never outlives an epoch boundary
+    let cache_key = format!("{}:{}", context.epoch, stake_pubkey);
+    let response = state.activations.get_or_try_insert(&cache_key, || {
+        compute_activation(&client, &context, &stake_pubkey)
+    })?;
+
+    info!(
+        "Stake {} in epoch {}: {} active, {} activating, {} deactivating",
+        stake_pubkey, response.epoch, response.active, response.activating, response.deactivating
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Stake activation fetched successfully");
+
+    Ok(Json(json_response))
+}
@@ -0,0 +1,542 @@
+use crate::utils::pubkey::parse_pubkey;
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+    routing::get,
+    Router,
+};
+use futures_util::{stream, SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::{PubsubClient, PubsubClientError};
+use solana_client::rpc_config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSignatureSubscribeConfig,
+};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, program_pack::Pack, pubkey::Pubkey, signature::Signature,
+};
+use spl_token_2022::state::Account;
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+use tokio::sync::{broadcast, mpsc, oneshot, Notify};
+use tracing::{info, warn};
+
+const TOPIC_BUFFER: usize = 256;
+const OUTGOING_BUFFER: usize = 256;
+const MAX_SUBSCRIPTIONS_PER_CONNECTION: usize = 100;
+// Byte offset of the owner in a token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+// Account streams only end on their own when the pubsub connection drops
+pub(crate) const UPSTREAM_CLOSED: &str = "Upstream subscription closed";
+
+#[derive(Error, Debug)]
+pub(crate) enum UpstreamError {
+    // The shared connection is unusable and has to be replaced
+    #[error("{0}")]
+    Connection(String),
+    // The node refused this subscription; the connection still serves other topics
+    #[error("{0}")]
+    Rejected(String),
+}
+
+impl From<PubsubClientError> for UpstreamError {
+    fn from(e: PubsubClientError) -> Self {
+        match e {
+            PubsubClientError::SubscribeFailed { .. } | PubsubClientError::RequestFailed { .. } => {
+                Self::Rejected(e.to_string())
+            }
+            _ => Self::Connection(e.to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ClientMessage {
+    // subscribe or unsubscribe
+    pub method: Option<String>,
+    // account, tokenBalances or signature
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub address: Option<String>,
+    pub signature: Option<String>,
+    // Key returned by subscribe, e.g. "account:<address>"
+    pub subscription: Option<String>,
+}
+
+#[derive(Clone)]
+enum TopicKind {
+    Account(Pubkey),
+    TokenBalances(Pubkey),
+    Signature(Signature),
+}
+
+#[derive(Clone)]
+enum TopicEvent {
+    Notification(serde_json::Value),
+    Error(String),
+}
+
+// One upstream subscription shared by every client subscribed to the same key
+struct Topic {
+    sender: broadcast::Sender<TopicEvent>,
+    stop: Arc<Notify>,
+}
+
+#[derive(Clone, Default)]
+pub struct StreamState {
+    topics: Arc<Mutex<HashMap<String, Topic>>>,
+    // A single connection to the RPC provider, reopened after it fails
+    pubsub: Arc<tokio::sync::Mutex<Option<Arc<PubsubClient>>>>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/ws", get(ws_handler))
+        .with_state(StreamState::default())
+}
+
+pub(crate) fn pubsub_url() -> String {
+    if let Ok(url) = env::var("SOLANA_WS_URL") {
+        return url;
+    }
+
+    // Derived from the HTTP endpoint, as RPC providers serve both on the same host
+    env::var("SOLANA_RPC_URL")
+        .unwrap_or_else(|_| "https://api.devnet.solana.com".to_string())
+        .replacen("http", "ws", 1)
+}
+
+impl StreamState {
+    async fn pubsub_client(&self) -> Result<Arc<PubsubClient>, String> {
+        let mut pubsub = self.pubsub.lock().await;
+
+        if let Some(client) = pubsub.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let url = pubsub_url();
+        let client = Arc::new(
+            PubsubClient::new(&url)
+                .await
+                .map_err(|e| format!("Failed to connect to {url}: {e}"))?,
+        );
+        info!("Connected to pubsub endpoint {}", url);
+
+        *pubsub = Some(client.clone());
+        Ok(client)
+    }
+
+    async fn reset_pubsub_client(&self, failed: &Arc<PubsubClient>) {
+        let mut pubsub = self.pubsub.lock().await;
+        if pubsub
+            .as_ref()
+            .is_some_and(|client| Arc::ptr_eq(client, failed))
+        {
+            *pubsub = None;
+        }
+    }
+
+    // Joins the topic for `key`, starting its upstream subscription if this is the first client
+    fn subscribe(&self, key: &str, kind: TopicKind) -> broadcast::Receiver<TopicEvent> {
+        let mut topics = self.topics.lock().unwrap();
+
+        if let Some(topic) = topics.get(key) {
+            return topic.sender.subscribe();
+        }
+
+        let (sender, receiver) = broadcast::channel(TOPIC_BUFFER);
+        let stop = Arc::new(Notify::new());
+
+        topics.insert(
+            key.to_string(),
+            Topic {
+                sender: sender.clone(),
+                stop: stop.clone(),
+            },
+        );
+
+        info!("Opening upstream subscription {}", key);
+        tokio::spawn(run_upstream(
+            self.clone(),
+            key.to_string(),
+            kind,
+            sender,
+            stop,
+        ));
+
+        receiver
+    }
+
+    // Stops the upstream subscription once its last client has left
+    fn release(&self, key: &str) {
+        let mut topics = self.topics.lock().unwrap();
+
+        if topics
+            .get(key)
+            .is_some_and(|topic| topic.sender.receiver_count() == 0)
+        {
+            if let Some(topic) = topics.remove(key) {
+                info!("Closing upstream subscription {}", key);
+                topic.stop.notify_one();
+            }
+        }
+    }
+
+    // Removes a topic whose upstream ended, unless it was already replaced
+    fn remove_topic(&self, key: &str, stop: &Arc<Notify>) {
+        let mut topics = self.topics.lock().unwrap();
+
+        if topics
+            .get(key)
+            .is_some_and(|topic| Arc::ptr_eq(&topic.stop, stop))
+        {
+            topics.remove(key);
+        }
+    }
+}
+
+fn account_config() -> RpcAccountInfoConfig {
+    RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::JsonParsed),
+        commitment: Some(CommitmentConfig::confirmed()),
+        ..Default::default()
+    }
+}
+
+// Token accounts of the owner under one token program
+fn token_accounts_config(owner: &Pubkey, program_id: &Pubkey) -> RpcProgramAccountsConfig {
+    let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        TOKEN_ACCOUNT_OWNER_OFFSET,
+        owner.as_ref(),
+    ))];
+
+    // Token-2022 accounts with extensions are larger than the base layout
+    if *program_id == spl_token::id() {
+        filters.push(RpcFilterType::DataSize(Account::LEN as u64));
+    }
+
+    RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: account_config(),
+        ..Default::default()
+    }
+}
+
+// Forwards upstream notifications to the topic; returns false if the upstream ended first
+async fn pump(
+    mut notifications: stream::BoxStream<'_, serde_json::Value>,
+    sender: &broadcast::Sender<TopicEvent>,
+    stop: &Notify,
+) -> bool {
+    loop {
+        tokio::select! {
+            _ = stop.notified() => return true,
+            notification = notifications.next() => match notification {
+                // Sending only fails while no client is listening, which `release` handles
+                Some(notification) => {
+                    let _ = sender.send(TopicEvent::Notification(notification));
+                }
+                None => return false,
+            },
+        }
+    }
+}
+
+async fn run_upstream(
+    state: StreamState,
+    key: String,
+    kind: TopicKind,
+    sender: broadcast::Sender<TopicEvent>,
+    stop: Arc<Notify>,
+) {
+    let result = async {
+        let client = state
+            .pubsub_client()
+            .await
+            .map_err(UpstreamError::Connection)?;
+
+        let subscribed = subscribe_upstream(&client, &kind, &sender, &stop).await;
+        if let Err(UpstreamError::Connection(_)) = subscribed {
+            state.reset_pubsub_client(&client).await;
+        }
+        subscribed
+    }
+    .await;
+
+    if let Err(e) = result {
+        warn!("Upstream subscription {} failed: {}", key, e);
+        let _ = sender.send(TopicEvent::Error(e.to_string()));
+    }
+
+    state.remove_topic(&key, &stop);
+    info!("Upstream subscription {} ended", key);
+}
+
+async fn subscribe_upstream(
+    client: &PubsubClient,
+    kind: &TopicKind,
+    sender: &broadcast::Sender<TopicEvent>,
+    stop: &Notify,
+) -> Result<(), UpstreamError> {
+    match kind {
+        TopicKind::Account(address) => {
+            let (notifications, unsubscribe) = client
+                .account_subscribe(address, Some(account_config()))
+                .await?;
+
+            let stopped = pump(
+                notifications
+                    .map(|response| serde_json::to_value(response).unwrap_or_default())
+                    .boxed(),
+                sender,
+                stop,
+            )
+            .await;
+            unsubscribe().await;
+
+            if !stopped {
+                return Err(UpstreamError::Connection(UPSTREAM_CLOSED.to_string()));
+            }
+        }
+        TopicKind::TokenBalances(owner) => {
+            let (token_notifications, token_unsubscribe) = client
+                .program_subscribe(
+                    &spl_token::id(),
+                    Some(token_accounts_config(owner, &spl_token::id())),
+                )
+                .await?;
+            let (token_2022_notifications, token_2022_unsubscribe) = match client
+                .program_subscribe(
+                    &spl_token_2022::id(),
+                    Some(token_accounts_config(owner, &spl_token_2022::id())),
+                )
+                .await
+            {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    // Don't leave the spl-token subscription open on a connection that is kept
+                    token_unsubscribe().await;
+                    return Err(e.into());
+                }
+            };
+
+            let stopped = pump(
+                stream::select(token_notifications, token_2022_notifications)
+                    .map(|response| serde_json::to_value(response).unwrap_or_default())
+                    .boxed(),
+                sender,
+                stop,
+            )
+            .await;
+            token_unsubscribe().await;
+            token_2022_unsubscribe().await;
+
+            if !stopped {
+                return Err(UpstreamError::Connection(UPSTREAM_CLOSED.to_string()));
+            }
+        }
+        TopicKind::Signature(signature) => {
+            let (notifications, unsubscribe) = client
+                .signature_subscribe(
+                    signature,
+                    Some(RpcSignatureSubscribeConfig {
+                        commitment: Some(CommitmentConfig::confirmed()),
+                        enable_received_notification: Some(false),
+                    }),
+                )
+                .await?;
+
+            // The node sends a single notification and then drops the subscription
+            let mut notified = false;
+            let stopped = pump(
+                notifications
+                    .take(1)
+                    .map(|response| {
+                        notified = true;
+                        serde_json::to_value(response).unwrap_or_default()
+                    })
+                    .boxed(),
+                sender,
+                stop,
+            )
+            .await;
+            unsubscribe().await;
+
+            // Ending before the notification means the connection dropped
+            if !stopped && !notified {
+                return Err(UpstreamError::Connection(UPSTREAM_CLOSED.to_string()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_topic(message: &ClientMessage) -> Result<(String, TopicKind), String> {
+    let kind = message.kind.as_deref().ok_or("Missing subscription type")?;
+
+    match kind {
+        "account" | "tokenBalances" => {
+            let address =
+                parse_pubkey(message.address.as_ref(), "address").map_err(|e| e.to_string())?;
+            let topic = if kind == "account" {
+                TopicKind::Account(address)
+            } else {
+                TopicKind::TokenBalances(address)
+            };
+            Ok((format!("{kind}:{address}"), topic))
+        }
+        "signature" => {
+            let signature = message
+                .signature
+                .as_ref()
+                .ok_or("Missing signature")?
+                .parse::<Signature>()
+                .map_err(|_| "Invalid signature".to_string())?;
+            Ok((
+                format!("signature:{signature}"),
+                TopicKind::Signature(signature),
+            ))
+        }
+        _ => Err("type must be account, tokenBalances or signature".to_string()),
+    }
+}
+
+// Relays a topic to one client until it unsubscribes, disconnects or the topic closes
+async fn forward(
+    state: StreamState,
+    key: String,
+    mut receiver: broadcast::Receiver<TopicEvent>,
+    outgoing: mpsc::Sender<Message>,
+    mut cancel: oneshot::Receiver<()>,
+) {
+    loop {
+        let event = tokio::select! {
+            _ = &mut cancel => break,
+            event = receiver.recv() => event,
+        };
+
+        let message = match event {
+            Ok(TopicEvent::Notification(data)) => {
+                json!({ "type": "notification", "subscription": key, "data": data })
+            }
+            Ok(TopicEvent::Error(error)) => {
+                json!({ "type": "error", "subscription": key, "error": error })
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                json!({ "type": "lagged", "subscription": key, "skipped": skipped })
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                let message = json!({ "type": "closed", "subscription": key });
+                let _ = outgoing.send(Message::Text(message.to_string())).await;
+                break;
+            }
+        };
+
+        if outgoing
+            .send(Message::Text(message.to_string()))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    drop(receiver);
+    state.release(&key);
+}
+
+async fn ws_handler(ws: WebSocketUpgrade, State(state): State<StreamState>) -> Response {
+    info!("GET /ws - WebSocket upgrade");
+
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(socket: WebSocket, state: StreamState) {
+    let (mut sink, mut incoming) = socket.split();
+    let (outgoing, mut outgoing_receiver) = mpsc::channel::<Message>(OUTGOING_BUFFER);
+
+    // A single writer so forwarders and replies never interleave partial frames
+    let writer = tokio::spawn(async move {
+        while let Some(message) = outgoing_receiver.recv().await {
+            if sink.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    // Dropping a cancel sender stops the matching forwarder
+    let mut subscriptions: HashMap<String, oneshot::Sender<()>> = HashMap::new();
+
+    while let Some(Ok(message)) = incoming.next().await {
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        // Finished forwarders (e.g. confirmed signatures) no longer count towards the limit
+        subscriptions.retain(|_, cancel| !cancel.is_closed());
+
+        let reply = match serde_json::from_str::<ClientMessage>(&text) {
+            Err(e) => json!({ "type": "error", "error": format!("Invalid message: {e}") }),
+            Ok(message) => match message.method.as_deref() {
+                Some("subscribe") => match parse_topic(&message) {
+                    Err(error) => json!({ "type": "error", "error": error }),
+                    Ok((key, _)) if subscriptions.contains_key(&key) => {
+                        json!({ "type": "subscribed", "subscription": key })
+                    }
+                    Ok(_) if subscriptions.len() >= MAX_SUBSCRIPTIONS_PER_CONNECTION => json!({
+                        "type": "error",
+                        "error": format!(
+                            "At most {MAX_SUBSCRIPTIONS_PER_CONNECTION} subscriptions per connection"
+                        )
+                    }),
+                    Ok((key, kind)) => {
+                        let receiver = state.subscribe(&key, kind);
+                        let (cancel, cancelled) = oneshot::channel();
+                        tokio::spawn(forward(
+                            state.clone(),
+                            key.clone(),
+                            receiver,
+                            outgoing.clone(),
+                            cancelled,
+                        ));
+                        subscriptions.insert(key.clone(), cancel);
+
+                        json!({ "type": "subscribed", "subscription": key })
+                    }
+                },
+                Some("unsubscribe") => {
+                    let key = message.subscription.unwrap_or_default();
+                    if subscriptions.remove(&key).is_some() {
+                        json!({ "type": "unsubscribed", "subscription": key })
+                    } else {
+                        json!({ "type": "error", "error": format!("Not subscribed to {key}") })
+                    }
+                }
+                _ => json!({ "type": "error", "error": "method must be subscribe or unsubscribe" }),
+            },
+        };
+
+        if outgoing
+            .send(Message::Text(reply.to_string()))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    // Cancels every forwarder; the writer exits once their senders are gone
+    drop(subscriptions);
+    drop(outgoing);
+    let _ = writer.await;
+}
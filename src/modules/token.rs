@@ -1,11 +1,40 @@
+use crate::modules::keypair::KeypairResponse;
+use crate::utils::amount::{format_ui_amount, resolve_amount, SOL_DECIMALS};
 use crate::utils::errors::SolanaError;
+use crate::utils::policy::{api_key, policy, Transfer};
 use crate::utils::solana_client::get_rpc_client;
-use axum::{routing::post, Json, Router};
+use axum::{
+    extract::Path,
+    http::HeaderMap,
+    routing::{get, post},
+    Json, Router,
+};
 use base64::{engine::general_purpose, Engine as _};
-use serde::{Deserialize, Serialize};
-use solana_sdk::pubkey::Pubkey;
-use spl_associated_token_account::get_associated_token_address;
-use spl_token::instruction::{initialize_mint, mint_to};
+use serde::{Deserialize, Deserializer, Serialize};
+use solana_account_decoder::parse_token_extension::{parse_extension, UiExtension};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token::instruction::{
+    initialize_mint, initialize_mint2, initialize_multisig, sync_native, MAX_SIGNERS,
+};
+use spl_token::state::Multisig;
+use spl_token_2022::extension::{BaseState, BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::instruction::{
+    approve, approve_checked, burn, burn_checked, close_account, freeze_account, revoke,
+    thaw_account, AuthorityType,
+};
+use spl_token_2022::state::{Account, AccountState, Mint};
 use tracing::info;
 
 #[derive(Deserialize, Serialize)]
@@ -14,6 +43,17 @@ pub struct CreateTokenRequest {
     pub mint_authority: Option<String>,
     pub mint: Option<String>,
     pub decimals: Option<u8>,
+    // When set, also create and fund the mint account from `payer`
+    #[serde(rename = "createAccount")]
+    pub create_account: Option<bool>,
+    pub payer: Option<String>,
+    // Absent defaults to the mint authority, explicit null disables freezing
+    #[serde(
+        rename = "freezeAuthority",
+        default,
+        deserialize_with = "deserialize_nullable"
+    )]
+    pub freeze_authority: Option<Option<String>>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -22,6 +62,196 @@ pub struct MintTokenRequest {
     pub destination: Option<String>,
     pub authority: Option<String>,
     pub amount: Option<u64>,
+    // Decimal alternative to `amount`, converted using the mint's decimals
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateMultisigRequest {
+    pub multisig: Option<String>,
+    pub signers: Option<Vec<String>>,
+    pub m: Option<u8>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SetAuthorityRequest {
+    // Mint for MintTokens/FreezeAccount, token account for AccountOwner/CloseAccount
+    pub account: Option<String>,
+    #[serde(rename = "authorityType")]
+    pub authority_type: Option<String>,
+    #[serde(rename = "currentAuthority")]
+    pub current_authority: Option<String>,
+    // Explicit null revokes the authority
+    #[serde(
+        rename = "newAuthority",
+        default,
+        deserialize_with = "deserialize_nullable"
+    )]
+    pub new_authority: Option<Option<String>>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct FreezeAccountRequest {
+    pub mint: Option<String>,
+    // Wallet owner whose ATA is targeted, unless `account` is given explicitly
+    pub owner: Option<String>,
+    pub account: Option<String>,
+    #[serde(rename = "freezeAuthority")]
+    pub freeze_authority: Option<String>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BurnTokenRequest {
+    pub mint: Option<String>,
+    pub owner: Option<String>,
+    pub amount: Option<u64>,
+    // Decimal alternative to `amount`, converted using the mint's decimals
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    // Must match the mint when provided; the checked variant is then used
+    pub decimals: Option<u8>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ApproveRequest {
+    pub mint: Option<String>,
+    pub owner: Option<String>,
+    pub delegate: Option<String>,
+    pub amount: Option<u64>,
+    // Decimal alternative to `amount`, converted using the mint's decimals
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    // Must match the mint when provided; the checked variant is then used
+    pub decimals: Option<u8>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RevokeRequest {
+    pub mint: Option<String>,
+    pub owner: Option<String>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CloseAccountRequest {
+    pub mint: Option<String>,
+    pub owner: Option<String>,
+    // Receives the reclaimed rent, defaults to the owner
+    pub destination: Option<String>,
+    #[serde(rename = "multisigSigners")]
+    pub multisig_signers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct WrapSolRequest {
+    pub owner: Option<String>,
+    pub lamports: Option<u64>,
+    // Decimal SOL alternative to `lamports`
+    #[serde(rename = "uiAmount")]
+    pub ui_amount: Option<String>,
+    // Funds the ATA creation, defaults to the owner
+    pub payer: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct UnwrapSolRequest {
+    pub owner: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct WrapSolResponse {
+    pub account: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lamports: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_amount: Option<String>,
+    pub instructions: Vec<InstructionResponse>,
+}
+
+#[derive(Serialize)]
+pub struct SetAuthorityResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub authority_type: String,
+    pub current_authority: String,
+    pub new_authority: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct MintInfoResponse {
+    pub address: String,
+    pub program_id: String,
+    pub supply: u64,
+    pub decimals: u8,
+    pub mint_authority: Option<String>,
+    pub freeze_authority: Option<String>,
+    pub is_initialized: bool,
+    pub extensions: Vec<UiExtension>,
+}
+
+#[derive(Serialize)]
+pub struct TokenAccountInfoResponse {
+    pub address: String,
+    pub program_id: String,
+    pub mint: String,
+    pub owner: String,
+    pub amount: u64,
+    pub delegate: Option<String>,
+    pub delegated_amount: u64,
+    pub state: String,
+    pub is_native: bool,
+    pub close_authority: Option<String>,
+    pub extensions: Vec<UiExtension>,
+}
+
+#[derive(Serialize)]
+pub struct MultisigResponse {
+    pub address: String,
+    pub m: u8,
+    pub n: u8,
+    pub is_initialized: bool,
+    pub signers: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CreateTokenResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub mint_authority: String,
+    pub freeze_authority: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TokenAmountResponse {
+    #[serde(flatten)]
+    pub instruction: InstructionResponse,
+    pub amount: u64,
+    pub ui_amount: String,
+    pub decimals: u8,
+}
+
+#[derive(Serialize)]
+pub struct CreateMintAccountResponse {
+    pub mint: String,
+    pub mint_authority: String,
+    pub freeze_authority: Option<String>,
+    pub rent_lamports: u64,
+    pub space: usize,
+    pub instructions: Vec<InstructionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mint_keypair: Option<KeypairResponse>,
 }
 
 #[derive(Serialize)]
@@ -42,6 +272,167 @@ pub fn routes() -> Router {
     Router::new()
         .route("/token/create", post(create_token))
         .route("/token/mint", post(mint_token))
+        .route("/token/multisig/create", post(create_multisig))
+        .route("/token/multisig/:address", get(get_multisig))
+        .route("/token/authority", post(set_token_authority))
+        .route("/token/freeze", post(freeze_token_account))
+        .route("/token/thaw", post(thaw_token_account))
+        .route("/token/burn", post(burn_token))
+        .route("/token/approve", post(approve_delegate))
+        .route("/token/revoke", post(revoke_delegate))
+        .route("/token/close", post(close_token_account))
+        .route("/token/wsol/wrap", post(wrap_sol))
+        .route("/token/wsol/unwrap", post(unwrap_sol))
+        .route("/token/mint/:address", get(get_mint_info))
+        .route("/token/account/:address", get(get_token_account_info))
+}
+
+// Decoded token state together with the program that owns it
+pub struct TokenState<S> {
+    pub program_id: Pubkey,
+    pub base: S,
+    pub extensions: Vec<UiExtension>,
+}
+
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+// Unpacks SPL Token and Token-2022 state alike; legacy accounts simply have no extensions
+pub fn decode_token_state<S: BaseState>(program_id: &Pubkey, data: &[u8]) -> Option<TokenState<S>> {
+    if !is_token_program(program_id) {
+        return None;
+    }
+
+    let state = StateWithExtensions::<S>::unpack(data).ok()?;
+    let extensions = state
+        .get_extension_types()
+        .ok()?
+        .iter()
+        .map(|extension_type| parse_extension(extension_type, &state))
+        .collect();
+
+    Some(TokenState {
+        program_id: *program_id,
+        base: state.base,
+        extensions,
+    })
+}
+
+// Distinguishes an explicit `null` (Some(None)) from an omitted field (None)
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+pub fn instruction_response(instruction: &Instruction) -> InstructionResponse {
+    InstructionResponse {
+        program_id: instruction.program_id.to_string(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|acc| AccountMetaResponse {
+                pubkey: acc.pubkey.to_string(),
+                is_signer: acc.is_signer,
+                is_writable: acc.is_writable,
+            })
+            .collect(),
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    }
+}
+
+pub fn fetch_mint(client: &RpcClient, mint: &Pubkey) -> Result<TokenState<Mint>, SolanaError> {
+    // Only a missing account is the caller's fault; RPC failures surface as 502
+    let account = client
+        .get_account_with_commitment(mint, client.commitment())?
+        .value
+        .ok_or_else(|| SolanaError::InvalidInput("Mint account does not exist".to_string()))?;
+
+    if !is_token_program(&account.owner) {
+        return Err(SolanaError::InvalidInput(
+            "Invalid mint account - not owned by a token program".to_string(),
+        ));
+    }
+
+    decode_token_state::<Mint>(&account.owner, &account.data)
+        .ok_or_else(|| SolanaError::InvalidInput("Account is not a token mint".to_string()))
+}
+
+pub fn fetch_token_account(
+    client: &RpcClient,
+    address: &Pubkey,
+) -> Result<TokenState<Account>, SolanaError> {
+    let account = client
+        .get_account_with_commitment(address, client.commitment())?
+        .value
+        .ok_or_else(|| SolanaError::InvalidInput("Token account does not exist".to_string()))?;
+
+    if !is_token_program(&account.owner) {
+        return Err(SolanaError::InvalidInput(
+            "Invalid token account - not owned by a token program".to_string(),
+        ));
+    }
+
+    decode_token_state::<Account>(&account.owner, &account.data)
+        .ok_or_else(|| SolanaError::InvalidInput("Account is not a token account".to_string()))
+}
+
+pub fn account_state_name(state: AccountState) -> &'static str {
+    match state {
+        AccountState::Uninitialized => "uninitialized",
+        AccountState::Initialized => "initialized",
+        AccountState::Frozen => "frozen",
+    }
+}
+
+// Ensures the on-chain authority matches the one the caller claims to hold
+fn check_authority(
+    name: &str,
+    on_chain: COption<Pubkey>,
+    provided: &Pubkey,
+) -> Result<(), SolanaError> {
+    match on_chain {
+        COption::Some(authority) if authority == *provided => Ok(()),
+        COption::Some(authority) => Err(SolanaError::AuthorityMismatch(format!(
+            "{name} is {authority}, not {provided}"
+        ))),
+        COption::None => Err(SolanaError::AuthorityMismatch(format!("{name} is not set"))),
+    }
+}
+
+// Parses the optional signer list used when an authority is an SPL Token multisig.
+// An empty list means the authority signs directly.
+pub fn parse_multisig_signers(signers: Option<&Vec<String>>) -> Result<Vec<Pubkey>, SolanaError> {
+    let Some(signers) = signers else {
+        return Ok(Vec::new());
+    };
+
+    if signers.len() > MAX_SIGNERS {
+        return Err(SolanaError::InvalidInput(format!(
+            "At most {MAX_SIGNERS} multisig signers are allowed"
+        )));
+    }
+
+    let pubkeys = signers
+        .iter()
+        .map(|s| {
+            s.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput(format!("Invalid multisig signer: {s}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (i, pubkey) in pubkeys.iter().enumerate() {
+        if pubkeys[..i].contains(pubkey) {
+            return Err(SolanaError::InvalidInput(format!(
+                "Duplicate multisig signer: {pubkey}"
+            )));
+        }
+    }
+
+    Ok(pubkeys)
 }
 
 async fn create_token(
@@ -59,11 +450,18 @@ async fn create_token(
         .filter(|s| !s.trim().is_empty())
         .ok_or(SolanaError::MissingFields)?;
 
-    let mint = payload
-        .mint
-        .as_ref()
-        .filter(|s| !s.trim().is_empty())
-        .ok_or(SolanaError::MissingFields)?;
+    let create_account = payload.create_account.unwrap_or(false);
+
+    // The mint may only be omitted when the server creates the account
+    let mint = payload.mint.as_ref().filter(|s| !s.trim().is_empty());
+    if mint.is_none() && !create_account {
+        return Err(SolanaError::MissingFields);
+    }
+
+    let payer = payload.payer.as_ref().filter(|s| !s.trim().is_empty());
+    if payer.is_none() && create_account {
+        return Err(SolanaError::MissingFields);
+    }
 
     let decimals = payload.decimals.ok_or(SolanaError::MissingFields)?;
 
@@ -79,9 +477,18 @@ async fn create_token(
         .parse::<Pubkey>()
         .map_err(|_| SolanaError::InvalidInput("Invalid mint authority public key".to_string()))?;
 
-    let mint_pubkey = mint
-        .parse::<Pubkey>()
-        .map_err(|_| SolanaError::InvalidInput("Invalid mint public key".to_string()))?;
+    // Generate the mint keypair server-side when none was provided
+    let (mint_pubkey, mint_keypair) = match mint {
+        Some(mint) => (
+            mint.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid mint public key".to_string()))?,
+            None,
+        ),
+        None => {
+            let keypair = Keypair::new();
+            (keypair.pubkey(), Some(keypair))
+        }
+    };
 
     // Validate that mint and authority are different accounts
     if mint_pubkey == mint_authority_pubkey {
@@ -90,38 +497,100 @@ async fn create_token(
         ));
     }
 
+    let freeze_authority_pubkey = match &payload.freeze_authority {
+        None => Some(mint_authority_pubkey),
+        Some(None) => None,
+        Some(Some(freeze_authority)) => Some(freeze_authority.parse::<Pubkey>().map_err(|_| {
+            SolanaError::InvalidInput("Invalid freeze authority public key".to_string())
+        })?),
+    };
+
     // For informational purposes, show what the ATA would look like for the mint authority
     let authority_ata = get_associated_token_address(&mint_authority_pubkey, &mint_pubkey);
 
     info!(
-        "Creating mint: {} with authority: {} (Authority's ATA would be: {})",
-        mint_pubkey, mint_authority_pubkey, authority_ata
+        "Creating mint: {} with authority: {} (Authority's ATA would be: {}), freeze authority: {:?}",
+        mint_pubkey, mint_authority_pubkey, authority_ata, freeze_authority_pubkey
     );
 
+    if let Some(payer) = payer.filter(|_| create_account) {
+        let payer_pubkey = payer
+            .parse::<Pubkey>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid payer public key".to_string()))?;
+
+        if payer_pubkey == mint_pubkey {
+            return Err(SolanaError::InvalidInput(
+                "Payer and mint account cannot be the same".to_string(),
+            ));
+        }
+
+        // Fund the new mint with the rent-exempt minimum for its size
+        let client = get_rpc_client();
+        let rent_lamports = client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+
+        info!(
+            "Creating mint account {} funded by {} with {} lamports",
+            mint_pubkey, payer_pubkey, rent_lamports
+        );
+
+        let create_instruction = system_instruction::create_account(
+            &payer_pubkey,
+            &mint_pubkey,
+            rent_lamports,
+            Mint::LEN as u64,
+            &spl_token::id(),
+        );
+
+        // initialize_mint2 does not require the rent sysvar account
+        let initialize_instruction = initialize_mint2(
+            &spl_token::id(),
+            &mint_pubkey,
+            &mint_authority_pubkey,
+            freeze_authority_pubkey.as_ref(),
+            decimals,
+        )
+        .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+        let response = CreateMintAccountResponse {
+            mint: mint_pubkey.to_string(),
+            mint_authority: mint_authority_pubkey.to_string(),
+            freeze_authority: freeze_authority_pubkey.map(|p| p.to_string()),
+            rent_lamports,
+            space: Mint::LEN,
+            instructions: vec![
+                instruction_response(&create_instruction),
+                instruction_response(&initialize_instruction),
+            ],
+            mint_keypair: mint_keypair.map(|keypair| KeypairResponse {
+                pubkey: keypair.pubkey().to_string(),
+                secret: bs58::encode(&keypair.to_bytes()).into_string(),
+            }),
+        };
+
+        let json_response = serde_json::json!({
+            "success": true,
+            "data": response
+        });
+
+        info!("Response: 200 - Mint account creation instructions generated successfully");
+
+        return Ok(Json(json_response));
+    }
+
     // Create initialize mint instruction
     let instruction = initialize_mint(
         &spl_token::id(),
         &mint_pubkey,
         &mint_authority_pubkey,
-        Some(&mint_authority_pubkey), // Using same authority for freeze authority
+        freeze_authority_pubkey.as_ref(),
         decimals,
     )
     .map_err(|e| SolanaError::TokenError(e.to_string()))?;
 
-    let accounts: Vec<AccountMetaResponse> = instruction
-        .accounts
-        .iter()
-        .map(|acc| AccountMetaResponse {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        })
-        .collect();
-
-    let response = InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let response = CreateTokenResponse {
+        instruction: instruction_response(&instruction),
+        mint_authority: mint_authority_pubkey.to_string(),
+        freeze_authority: freeze_authority_pubkey.map(|p| p.to_string()),
     };
 
     let json_response = serde_json::json!({
@@ -135,6 +604,7 @@ async fn create_token(
 }
 
 async fn mint_token(
+    headers: HeaderMap,
     Json(payload): Json<MintTokenRequest>,
 ) -> Result<Json<serde_json::Value>, SolanaError> {
     info!(
@@ -161,10 +631,9 @@ async fn mint_token(
         .filter(|s| !s.trim().is_empty())
         .ok_or(SolanaError::MissingFields)?;
 
-    let amount = payload
-        .amount
-        .filter(|&a| a > 0)
-        .ok_or(SolanaError::MissingFields)?;
+    if payload.amount.is_none() && payload.ui_amount.is_none() {
+        return Err(SolanaError::MissingFields);
+    }
 
     // Parse public keys AFTER validation
     let mint_pubkey = mint
@@ -179,24 +648,57 @@ async fn mint_token(
         .parse::<Pubkey>()
         .map_err(|_| SolanaError::InvalidInput("Invalid authority address".to_string()))?;
 
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
     // Get RPC client for validation
     let client = get_rpc_client();
 
     // Validate mint account exists and check authority permissions
-    let mint_account = client
-        .get_account(&mint_pubkey)
-        .map_err(|_| SolanaError::InvalidInput("Mint account does not exist".to_string()))?;
+    let mint_state = fetch_mint(&client, &mint_pubkey)?;
+    let program_id = mint_state.program_id;
 
-    // Parse mint account data to check authority
-    if mint_account.owner != spl_token::id() {
-        return Err(SolanaError::InvalidInput(
-            "Invalid mint account - not owned by SPL Token program".to_string(),
+    // A revoked mint authority means the supply is fixed
+    if mint_state.base.mint_authority.is_none() {
+        return Err(SolanaError::AuthorityMismatch(
+            "Minting is disabled - the mint authority has been revoked".to_string(),
         ));
     }
 
+    check_authority(
+        "Mint authority",
+        mint_state.base.mint_authority,
+        &authority_pubkey,
+    )?;
+
+    let decimals = mint_state.base.decimals;
+    let amount = resolve_amount(payload.amount, payload.ui_amount.as_ref(), decimals)?;
+
+    if mint_state.base.supply.checked_add(amount).is_none() {
+        return Err(SolanaError::TokenError(format!(
+            "Minting {} would overflow the current supply of {}",
+            amount, mint_state.base.supply
+        )));
+    }
+
+    policy().enforce(
+        "/token/mint",
+        api_key(&headers),
+        &[Transfer {
+            recipient: destination_wallet_pubkey,
+            mint: Some(mint_pubkey),
+            amount,
+        }],
+    )?;
+
     // Derive ATAs for both authority and destination
-    let authority_ata = get_associated_token_address(&authority_pubkey, &mint_pubkey);
-    let destination_ata = get_associated_token_address(&destination_wallet_pubkey, &mint_pubkey);
+    let authority_ata =
+        get_associated_token_address_with_program_id(&authority_pubkey, &mint_pubkey, &program_id);
+    let destination_ata = get_associated_token_address_with_program_id(
+        &destination_wallet_pubkey,
+        &mint_pubkey,
+        &program_id,
+    );
 
     info!(
         "Authority: {} (ATA: {}), Destination: {} (ATA: {})",
@@ -239,30 +741,21 @@ async fn mint_token(
     );
 
     // Create mint to instruction using the derived ATA
-    let instruction = mint_to(
-        &spl_token::id(),
+    let instruction = spl_token_2022::instruction::mint_to(
+        &program_id,
         &mint_pubkey,
         &destination_ata,
         &authority_pubkey,
-        &[],
+        &signer_refs,
         amount,
     )
     .map_err(|e| SolanaError::TokenError(e.to_string()))?;
 
-    let accounts: Vec<AccountMetaResponse> = instruction
-        .accounts
-        .iter()
-        .map(|acc| AccountMetaResponse {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        })
-        .collect();
-
-    let response = InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let response = TokenAmountResponse {
+        instruction: instruction_response(&instruction),
+        amount,
+        ui_amount: format_ui_amount(amount, decimals),
+        decimals,
     };
 
     let json_response = serde_json::json!({
@@ -276,3 +769,963 @@ async fn mint_token(
 
     Ok(Json(json_response))
 }
+
+async fn create_multisig(
+    Json(payload): Json<CreateMultisigRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/multisig/create - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let multisig = payload
+        .multisig
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let signers = payload
+        .signers
+        .as_ref()
+        .filter(|s| !s.is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let m = payload.m.ok_or(SolanaError::MissingFields)?;
+
+    let multisig_pubkey = multisig
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid multisig address".to_string()))?;
+
+    let signer_pubkeys = parse_multisig_signers(Some(signers))?;
+
+    // Threshold must be reachable with the provided signers
+    if m == 0 || m as usize > signer_pubkeys.len() {
+        return Err(SolanaError::InvalidInput(format!(
+            "Threshold m must be between 1 and the number of signers ({})",
+            signer_pubkeys.len()
+        )));
+    }
+
+    if signer_pubkeys.contains(&multisig_pubkey) {
+        return Err(SolanaError::InvalidInput(
+            "Multisig account cannot be one of its own signers".to_string(),
+        ));
+    }
+
+    info!(
+        "Creating {}-of-{} multisig: {}",
+        m,
+        signer_pubkeys.len(),
+        multisig_pubkey
+    );
+
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+
+    let instruction = initialize_multisig(&spl_token::id(), &multisig_pubkey, &signer_refs, m)
+        .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": instruction_response(&instruction)
+    });
+
+    info!("Response: 200 - Multisig initialization instruction generated successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_multisig(Path(address): Path<String>) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /token/multisig/{}", address);
+
+    let multisig_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid multisig address".to_string()))?;
+
+    let client = get_rpc_client();
+
+    let account = client
+        .get_account_with_commitment(&multisig_pubkey, client.commitment())?
+        .value
+        .ok_or_else(|| SolanaError::InvalidInput("Multisig account does not exist".to_string()))?;
+
+    // The multisig layout is shared by SPL Token and Token-2022
+    if !is_token_program(&account.owner) {
+        return Err(SolanaError::InvalidInput(
+            "Invalid multisig account - not owned by a token program".to_string(),
+        ));
+    }
+
+    let multisig = Multisig::unpack(&account.data)
+        .map_err(|_| SolanaError::InvalidInput("Account is not a token multisig".to_string()))?;
+
+    let response = MultisigResponse {
+        address: multisig_pubkey.to_string(),
+        m: multisig.m,
+        n: multisig.n,
+        is_initialized: multisig.is_initialized,
+        signers: multisig.signers[..multisig.n as usize]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Multisig account decoded successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn set_token_authority(
+    Json(payload): Json<SetAuthorityRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/authority - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let account = payload
+        .account
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let authority_type = payload
+        .authority_type
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let current_authority = payload
+        .current_authority
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    // The new authority must be given explicitly, either as a key or as null
+    let new_authority = payload
+        .new_authority
+        .as_ref()
+        .ok_or(SolanaError::MissingFields)?;
+
+    let authority_type = match authority_type.as_str() {
+        "MintTokens" => AuthorityType::MintTokens,
+        "FreezeAccount" => AuthorityType::FreezeAccount,
+        "AccountOwner" => AuthorityType::AccountOwner,
+        "CloseAccount" => AuthorityType::CloseAccount,
+        _ => return Err(SolanaError::InvalidInput(
+            "Authority type must be one of MintTokens, FreezeAccount, AccountOwner, CloseAccount"
+                .to_string(),
+        )),
+    };
+
+    // Parse public keys AFTER validation
+    let account_pubkey = account
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid account address".to_string()))?;
+
+    let current_authority_pubkey = current_authority
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid current authority address".to_string()))?;
+
+    let new_authority_pubkey = new_authority
+        .as_ref()
+        .map(|s| {
+            s.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid new authority address".to_string()))
+        })
+        .transpose()?;
+
+    if authority_type == AuthorityType::AccountOwner && new_authority_pubkey.is_none() {
+        return Err(SolanaError::InvalidInput(
+            "Token account owner cannot be revoked".to_string(),
+        ));
+    }
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    // Validate the current authority against on-chain state
+    let client = get_rpc_client();
+
+    let program_id = match authority_type {
+        AuthorityType::MintTokens => {
+            let mint = fetch_mint(&client, &account_pubkey)?;
+            check_authority(
+                "Mint authority",
+                mint.base.mint_authority,
+                &current_authority_pubkey,
+            )?;
+            mint.program_id
+        }
+        AuthorityType::FreezeAccount => {
+            let mint = fetch_mint(&client, &account_pubkey)?;
+            check_authority(
+                "Freeze authority",
+                mint.base.freeze_authority,
+                &current_authority_pubkey,
+            )?;
+            mint.program_id
+        }
+        AuthorityType::AccountOwner => {
+            let token_account = fetch_token_account(&client, &account_pubkey)?;
+            check_authority(
+                "Account owner",
+                COption::Some(token_account.base.owner),
+                &current_authority_pubkey,
+            )?;
+            token_account.program_id
+        }
+        AuthorityType::CloseAccount => {
+            // The owner acts as close authority when none is set
+            let token_account = fetch_token_account(&client, &account_pubkey)?;
+            let close_authority = token_account
+                .base
+                .close_authority
+                .or(COption::Some(token_account.base.owner));
+            check_authority(
+                "Close authority",
+                close_authority,
+                &current_authority_pubkey,
+            )?;
+            token_account.program_id
+        }
+        _ => {
+            return Err(SolanaError::InvalidInput(
+                "Unsupported authority type".to_string(),
+            ))
+        }
+    };
+
+    info!(
+        "Setting {:?} authority on {} from {} to {:?}",
+        authority_type, account_pubkey, current_authority_pubkey, new_authority_pubkey
+    );
+
+    let response_type = format!("{:?}", authority_type);
+
+    let instruction = spl_token_2022::instruction::set_authority(
+        &program_id,
+        &account_pubkey,
+        new_authority_pubkey.as_ref(),
+        authority_type,
+        &current_authority_pubkey,
+        &signer_refs,
+    )
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let response = SetAuthorityResponse {
+        instruction: instruction_response(&instruction),
+        authority_type: response_type,
+        current_authority: current_authority_pubkey.to_string(),
+        new_authority: new_authority_pubkey.map(|p| p.to_string()),
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Set authority instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn freeze_token_account(
+    Json(payload): Json<FreezeAccountRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/freeze - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    build_freeze_instruction(payload, true)
+}
+
+async fn thaw_token_account(
+    Json(payload): Json<FreezeAccountRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/thaw - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    build_freeze_instruction(payload, false)
+}
+
+fn build_freeze_instruction(
+    payload: FreezeAccountRequest,
+    freeze: bool,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    // Validate required fields are present and not empty
+    let mint = payload
+        .mint
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let freeze_authority = payload
+        .freeze_authority
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let account = payload.account.as_ref().filter(|s| !s.trim().is_empty());
+    let owner = payload.owner.as_ref().filter(|s| !s.trim().is_empty());
+
+    if account.is_none() && owner.is_none() {
+        return Err(SolanaError::MissingFields);
+    }
+
+    // Parse public keys AFTER validation
+    let mint_pubkey = mint
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let freeze_authority_pubkey = freeze_authority
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid freeze authority address".to_string()))?;
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    // Validate the freeze authority and the account state on-chain
+    let client = get_rpc_client();
+
+    let mint_state = fetch_mint(&client, &mint_pubkey)?;
+    let program_id = mint_state.program_id;
+
+    check_authority(
+        "Freeze authority",
+        mint_state.base.freeze_authority,
+        &freeze_authority_pubkey,
+    )?;
+
+    let account_pubkey = match (account, owner) {
+        (Some(account), _) => account
+            .parse::<Pubkey>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid token account address".to_string()))?,
+        (None, Some(owner)) => {
+            let owner_pubkey = owner
+                .parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+            get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &program_id)
+        }
+        (None, None) => return Err(SolanaError::MissingFields),
+    };
+
+    let token_account = fetch_token_account(&client, &account_pubkey)?;
+
+    if token_account.base.mint != mint_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Token account does not belong to the given mint".to_string(),
+        ));
+    }
+
+    match (freeze, token_account.base.state) {
+        (true, AccountState::Frozen) => {
+            return Err(SolanaError::InvalidInput(
+                "Token account is already frozen".to_string(),
+            ))
+        }
+        (false, AccountState::Initialized) => {
+            return Err(SolanaError::InvalidInput(
+                "Token account is not frozen".to_string(),
+            ))
+        }
+        (_, AccountState::Uninitialized) => {
+            return Err(SolanaError::InvalidInput(
+                "Token account is not initialized".to_string(),
+            ))
+        }
+        _ => {}
+    }
+
+    info!(
+        "{} token account {} of mint {} with freeze authority {}",
+        if freeze { "Freezing" } else { "Thawing" },
+        account_pubkey,
+        mint_pubkey,
+        freeze_authority_pubkey
+    );
+
+    let instruction = if freeze {
+        freeze_account(
+            &program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &freeze_authority_pubkey,
+            &signer_refs,
+        )
+    } else {
+        thaw_account(
+            &program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &freeze_authority_pubkey,
+            &signer_refs,
+        )
+    }
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": instruction_response(&instruction)
+    });
+
+    info!(
+        "Response: 200 - {} instruction created successfully",
+        if freeze { "Freeze" } else { "Thaw" }
+    );
+
+    Ok(Json(json_response))
+}
+
+async fn burn_token(
+    Json(payload): Json<BurnTokenRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/burn - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let mint = payload
+        .mint
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    if payload.amount.is_none() && payload.ui_amount.is_none() {
+        return Err(SolanaError::MissingFields);
+    }
+
+    // Parse public keys AFTER validation
+    let mint_pubkey = mint
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    // The mint's owner decides between the legacy token program and Token-2022
+    let mint_state = fetch_mint(&get_rpc_client(), &mint_pubkey)?;
+    let program_id = mint_state.program_id;
+
+    let decimals = mint_state.base.decimals;
+
+    if payload.decimals.is_some_and(|d| d != decimals) {
+        return Err(SolanaError::InvalidInput(format!(
+            "Decimals do not match the mint ({decimals})"
+        )));
+    }
+
+    let amount = resolve_amount(payload.amount, payload.ui_amount.as_ref(), decimals)?;
+
+    let source_ata =
+        get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &program_id);
+
+    info!(
+        "Burning {} tokens of mint {} from owner {} (ATA: {})",
+        amount, mint_pubkey, owner_pubkey, source_ata
+    );
+
+    // Only use the checked variant when the caller asserted the decimals
+    let instruction = match payload.decimals {
+        Some(_) => burn_checked(
+            &program_id,
+            &source_ata,
+            &mint_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        ),
+        None => burn(
+            &program_id,
+            &source_ata,
+            &mint_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+        ),
+    }
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let response = TokenAmountResponse {
+        instruction: instruction_response(&instruction),
+        amount,
+        ui_amount: format_ui_amount(amount, decimals),
+        decimals,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Burn instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn approve_delegate(
+    Json(payload): Json<ApproveRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/approve - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let mint = payload
+        .mint
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let delegate = payload
+        .delegate
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    if payload.amount.is_none() && payload.ui_amount.is_none() {
+        return Err(SolanaError::MissingFields);
+    }
+
+    // Parse public keys AFTER validation
+    let mint_pubkey = mint
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    let delegate_pubkey = delegate
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid delegate address".to_string()))?;
+
+    if owner_pubkey == delegate_pubkey {
+        return Err(SolanaError::InvalidInput(
+            "Token owner and delegate cannot be the same".to_string(),
+        ));
+    }
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    // The mint's owner decides between the legacy token program and Token-2022
+    let mint_state = fetch_mint(&get_rpc_client(), &mint_pubkey)?;
+    let program_id = mint_state.program_id;
+
+    let decimals = mint_state.base.decimals;
+
+    if payload.decimals.is_some_and(|d| d != decimals) {
+        return Err(SolanaError::InvalidInput(format!(
+            "Decimals do not match the mint ({decimals})"
+        )));
+    }
+
+    let amount = resolve_amount(payload.amount, payload.ui_amount.as_ref(), decimals)?;
+
+    let source_ata =
+        get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &program_id);
+
+    info!(
+        "Approving delegate {} for {} tokens of mint {} from owner {} (ATA: {})",
+        delegate_pubkey, amount, mint_pubkey, owner_pubkey, source_ata
+    );
+
+    // Only use the checked variant when the caller asserted the decimals
+    let instruction = match payload.decimals {
+        Some(_) => approve_checked(
+            &program_id,
+            &source_ata,
+            &mint_pubkey,
+            &delegate_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        ),
+        None => approve(
+            &program_id,
+            &source_ata,
+            &delegate_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+        ),
+    }
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let response = TokenAmountResponse {
+        instruction: instruction_response(&instruction),
+        amount,
+        ui_amount: format_ui_amount(amount, decimals),
+        decimals,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Approve instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn revoke_delegate(
+    Json(payload): Json<RevokeRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/revoke - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let mint = payload
+        .mint
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    // Parse public keys AFTER validation
+    let mint_pubkey = mint
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    let program_id = fetch_mint(&get_rpc_client(), &mint_pubkey)?.program_id;
+
+    let source_ata =
+        get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &program_id);
+
+    info!(
+        "Revoking delegate of mint {} for owner {} (ATA: {})",
+        mint_pubkey, owner_pubkey, source_ata
+    );
+
+    let instruction = revoke(&program_id, &source_ata, &owner_pubkey, &signer_refs)
+        .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": instruction_response(&instruction)
+    });
+
+    info!("Response: 200 - Revoke instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn close_token_account(
+    Json(payload): Json<CloseAccountRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/close - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let mint = payload
+        .mint
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    // Parse public keys AFTER validation
+    let mint_pubkey = mint
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    // Rent goes back to the owner unless another destination is given
+    let destination_pubkey = match payload
+        .destination
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+    {
+        Some(destination) => destination
+            .parse::<Pubkey>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid destination address".to_string()))?,
+        None => owner_pubkey,
+    };
+
+    let multisig_signers = parse_multisig_signers(payload.multisig_signers.as_ref())?;
+    let signer_refs: Vec<&Pubkey> = multisig_signers.iter().collect();
+
+    let program_id = fetch_mint(&get_rpc_client(), &mint_pubkey)?.program_id;
+
+    let account_ata =
+        get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &program_id);
+
+    if destination_pubkey == account_ata {
+        return Err(SolanaError::InvalidInput(
+            "Rent destination cannot be the account being closed".to_string(),
+        ));
+    }
+
+    info!(
+        "Closing token account {} of owner {} (mint {}), rent to {}",
+        account_ata, owner_pubkey, mint_pubkey, destination_pubkey
+    );
+
+    let instruction = close_account(
+        &program_id,
+        &account_ata,
+        &destination_pubkey,
+        &owner_pubkey,
+        &signer_refs,
+    )
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": instruction_response(&instruction)
+    });
+
+    info!("Response: 200 - Close account instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn wrap_sol(
+    Json(payload): Json<WrapSolRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/wsol/wrap - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let lamports = resolve_amount(payload.lamports, payload.ui_amount.as_ref(), SOL_DECIMALS)?;
+
+    // Parse public keys AFTER validation
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    let payer_pubkey = match payload.payer.as_ref().filter(|s| !s.trim().is_empty()) {
+        Some(payer) => payer
+            .parse::<Pubkey>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid payer address".to_string()))?,
+        None => owner_pubkey,
+    };
+
+    let native_mint = spl_token::native_mint::id();
+    let wsol_ata = get_associated_token_address(&owner_pubkey, &native_mint);
+
+    info!(
+        "Wrapping {} lamports for owner {} into wSOL account {}",
+        lamports, owner_pubkey, wsol_ata
+    );
+
+    // Order matters: the account must exist before it is funded and synced
+    let create_ata_instruction = create_associated_token_account_idempotent(
+        &payer_pubkey,
+        &owner_pubkey,
+        &native_mint,
+        &spl_token::id(),
+    );
+
+    let transfer_instruction = system_instruction::transfer(&owner_pubkey, &wsol_ata, lamports);
+
+    let sync_instruction = sync_native(&spl_token::id(), &wsol_ata)
+        .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let response = WrapSolResponse {
+        account: wsol_ata.to_string(),
+        lamports: Some(lamports),
+        ui_amount: Some(format_ui_amount(lamports, SOL_DECIMALS)),
+        instructions: vec![
+            instruction_response(&create_ata_instruction),
+            instruction_response(&transfer_instruction),
+            instruction_response(&sync_instruction),
+        ],
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - wSOL wrap instructions created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn unwrap_sol(
+    Json(payload): Json<UnwrapSolRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /token/wsol/unwrap - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    // Validate required fields are present and not empty
+    let owner = payload
+        .owner
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    // Parse public keys AFTER validation
+    let owner_pubkey = owner
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid owner address".to_string()))?;
+
+    let wsol_ata = get_associated_token_address(&owner_pubkey, &spl_token::native_mint::id());
+
+    info!(
+        "Unwrapping wSOL account {} back to owner {}",
+        wsol_ata, owner_pubkey
+    );
+
+    // Closing a native account returns both the wrapped lamports and the rent
+    let instruction = close_account(
+        &spl_token::id(),
+        &wsol_ata,
+        &owner_pubkey,
+        &owner_pubkey,
+        &[],
+    )
+    .map_err(|e| SolanaError::TokenError(e.to_string()))?;
+
+    let response = WrapSolResponse {
+        account: wsol_ata.to_string(),
+        lamports: None,
+        ui_amount: None,
+        instructions: vec![instruction_response(&instruction)],
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - wSOL unwrap instruction created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_mint_info(
+    Path(address): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /token/mint/{}", address);
+
+    let mint_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid mint address".to_string()))?;
+
+    let client = get_rpc_client();
+    let mint = fetch_mint(&client, &mint_pubkey)?;
+
+    let response = MintInfoResponse {
+        address: mint_pubkey.to_string(),
+        program_id: mint.program_id.to_string(),
+        supply: mint.base.supply,
+        decimals: mint.base.decimals,
+        mint_authority: Option::<Pubkey>::from(mint.base.mint_authority).map(|p| p.to_string()),
+        freeze_authority: Option::<Pubkey>::from(mint.base.freeze_authority).map(|p| p.to_string()),
+        is_initialized: mint.base.is_initialized,
+        extensions: mint.extensions,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Mint account decoded successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_token_account_info(
+    Path(address): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /token/account/{}", address);
+
+    let account_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid token account address".to_string()))?;
+
+    let client = get_rpc_client();
+    let account = fetch_token_account(&client, &account_pubkey)?;
+
+    let response = TokenAccountInfoResponse {
+        address: account_pubkey.to_string(),
+        program_id: account.program_id.to_string(),
+        mint: account.base.mint.to_string(),
+        owner: account.base.owner.to_string(),
+        amount: account.base.amount,
+        delegate: Option::<Pubkey>::from(account.base.delegate).map(|p| p.to_string()),
+        delegated_amount: account.base.delegated_amount,
+        state: account_state_name(account.base.state).to_string(),
+        is_native: account.base.is_native.is_some(),
+        close_authority: Option::<Pubkey>::from(account.base.close_authority)
+            .map(|p| p.to_string()),
+        extensions: account.extensions,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Token account decoded successfully");
+
+    Ok(Json(json_response))
+}
@@ -0,0 +1,175 @@
+use crate::utils::amount::{format_ui_amount, SOL_DECIMALS};
+use crate::utils::cache::TtlCache;
+use crate::utils::errors::SolanaError;
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_response::{RpcVoteAccountInfo, RpcVoteAccountStatus};
+use tracing::info;
+
+const VOTE_ACCOUNTS_KEY: &str = "vote_accounts";
+
+#[derive(Deserialize, Serialize)]
+pub struct ValidatorsQuery {
+    // activatedStake (default), commission or lastVote
+    pub sort: Option<String>,
+    // asc or desc (default)
+    pub order: Option<String>,
+    // Only delinquent (true) or only current (false) validators
+    pub delinquent: Option<bool>,
+    #[serde(rename = "maxCommission")]
+    pub max_commission: Option<u8>,
+    #[serde(rename = "minActivatedStake")]
+    pub min_activated_stake: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct ValidatorResponse {
+    pub vote_account: String,
+    pub node_pubkey: String,
+    pub commission: u8,
+    pub activated_stake: u64,
+    pub activated_stake_sol: String,
+    pub last_vote: u64,
+    pub root_slot: u64,
+    pub epoch_credits: Option<u64>,
+    pub delinquent: bool,
+}
+
+#[derive(Serialize)]
+pub struct ValidatorsResponse {
+    pub total: usize,
+    pub validators: Vec<ValidatorResponse>,
+}
+
+#[derive(Clone)]
+pub struct ValidatorsState {
+    vote_accounts: TtlCache<RpcVoteAccountStatus>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/validators", get(get_validators))
+        .with_state(ValidatorsState {
+            vote_accounts: TtlCache::from_env(),
+        })
+}
+
+fn validator_response(vote_account: RpcVoteAccountInfo, delinquent: bool) -> ValidatorResponse {
+    // Credits earned in the most recent epoch
+    let epoch_credits = vote_account
+        .epoch_credits
+        .last()
+        .map(|(_, credits, prev_credits)| credits.saturating_sub(*prev_credits));
+
+    ValidatorResponse {
+        vote_account: vote_account.vote_pubkey,
+        node_pubkey: vote_account.node_pubkey,
+        commission: vote_account.commission,
+        activated_stake: vote_account.activated_stake,
+        activated_stake_sol: format_ui_amount(vote_account.activated_stake, SOL_DECIMALS),
+        last_vote: vote_account.last_vote,
+        root_slot: vote_account.root_slot,
+        epoch_credits,
+        delinquent,
+    }
+}
+
+async fn get_validators(
+    State(state): State<ValidatorsState>,
+    Query(query): Query<ValidatorsQuery>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "GET /validators - Query: {}",
+        serde_json::to_string(&query).unwrap_or_default()
+    );
+
+    let sort = query.sort.as_deref().unwrap_or("activatedStake");
+    if !matches!(sort, "activatedStake" | "commission" | "lastVote") {
+        return Err(SolanaError::InvalidInput(
+            "sort must be activatedStake, commission or lastVote".to_string(),
+        ));
+    }
+
+    let descending = match query.order.as_deref().unwrap_or("desc") {
+        "desc" => true,
+        "asc" => false,
+        _ => {
+            return Err(SolanaError::InvalidInput(
+                "order must be asc or desc".to_string(),
+            ))
+        }
+    };
+
+    let vote_accounts = state
+        .vote_accounts
+        .get_or_try_insert(VOTE_ACCOUNTS_KEY, || {
+            get_rpc_client()
+                .get_vote_accounts()
+                .map_err(SolanaError::from)
+        })?;
+
+    let mut validators: Vec<ValidatorResponse> = vote_accounts
+        .current
+        .into_iter()
+        .map(|vote_account| validator_response(vote_account, false))
+        .chain(
+            vote_accounts
+                .delinquent
+                .into_iter()
+                .map(|vote_account| validator_response(vote_account, true)),
+        )
+        .filter(|v| {
+            query
+                .delinquent
+                .is_none_or(|delinquent| v.delinquent == delinquent)
+        })
+        .filter(|v| query.max_commission.is_none_or(|max| v.commission <= max))
+        .filter(|v| {
+            query
+                .min_activated_stake
+                .is_none_or(|min| v.activated_stake >= min)
+        })
+        .collect();
+
+    validators.sort_by(|a, b| {
+        let ordering = match sort {
+            "commission" => a.commission.cmp(&b.commission),
+            "lastVote" => a.last_vote.cmp(&b.last_vote),
+            _ => a.activated_stake.cmp(&b.activated_stake),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let total = validators.len();
+    if let Some(limit) = query.limit {
+        validators.truncate(limit);
+    }
+
+    info!(
+        "Returning {} of {} validators sorted by {}",
+        validators.len(),
+        total,
+        sort
+    );
+
+    let response = ValidatorsResponse { total, validators };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Validators fetched successfully");
+
+    Ok(Json(json_response))
+}
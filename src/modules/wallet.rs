@@ -0,0 +1,553 @@
+use crate::utils::amount::{format_ui_amount, SOL_DECIMALS};
+use crate::utils::errors::SolanaError;
+use crate::utils::solana_client::get_rpc_client;
+use axum::{
+    extract::{Path, Query},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use solana_account_decoder::parse_token::{TokenAccountType, UiAccountState};
+use solana_account_decoder::UiAccountData;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature, stake,
+    system_program,
+};
+use solana_transaction_status::parse_instruction::{ParsedInstruction, ParsedInstructionEnum};
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInnerInstructions,
+    UiInstruction, UiMessage, UiParsedInstruction, UiTransactionEncoding,
+    UiTransactionTokenBalance,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::{info, warn};
+
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+const MAX_HISTORY_LIMIT: usize = 100;
+
+#[derive(Serialize)]
+pub struct TokenAccountBalance {
+    pub address: String,
+    pub amount: u64,
+    pub ui_amount: String,
+    pub is_ata: bool,
+    pub is_frozen: bool,
+    pub delegate: Option<String>,
+    pub delegated_amount: u64,
+}
+
+#[derive(Serialize)]
+pub struct MintBalance {
+    pub mint: String,
+    pub program_id: String,
+    pub decimals: u8,
+    pub amount: u64,
+    pub ui_amount: String,
+    pub accounts: Vec<TokenAccountBalance>,
+}
+
+#[derive(Serialize)]
+pub struct WalletBalancesResponse {
+    pub address: String,
+    pub lamports: u64,
+    pub sol: String,
+    pub tokens: Vec<MintBalance>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct WalletHistoryQuery {
+    // Page cursors: only signatures older than `before` and newer than `until`
+    pub before: Option<String>,
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct TokenChange {
+    pub mint: String,
+    pub decimals: u8,
+    // Signed base units, as a string since it may not fit in a JSON number
+    pub change: String,
+    pub ui_change: String,
+}
+
+#[derive(Serialize)]
+pub struct WalletAction {
+    // transfer, mint, burn or stake
+    pub kind: String,
+    pub instruction: String,
+    pub program_id: String,
+    pub source: Option<String>,
+    pub destination: Option<String>,
+    pub authority: Option<String>,
+    // None for SOL and stake actions
+    pub mint: Option<String>,
+    pub amount: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct WalletHistoryEntry {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    // success or failed
+    pub status: String,
+    pub error: Option<String>,
+    // fee, fee_payer and sol_change are null when the node could not return the transaction
+    pub fee: Option<u64>,
+    pub fee_payer: Option<String>,
+    // Lamports, including the fee when the wallet paid it
+    pub sol_change: Option<i64>,
+    pub sol_change_ui: Option<String>,
+    pub token_changes: Vec<TokenChange>,
+    pub actions: Vec<WalletAction>,
+}
+
+#[derive(Serialize)]
+pub struct WalletHistoryResponse {
+    pub address: String,
+    pub transactions: Vec<WalletHistoryEntry>,
+    // Pass as `before` to fetch the next page; None on the last page
+    pub next_before: Option<String>,
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/wallet/:address/balances", get(get_balances))
+        .route("/wallet/:address/history", get(get_history))
+}
+
+async fn get_balances(Path(address): Path<String>) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /wallet/{}/balances", address);
+
+    let owner_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid wallet address".to_string()))?;
+
+    let client = get_rpc_client();
+
+    let lamports = client.get_balance(&owner_pubkey)?;
+
+    // Grouped by mint; BTreeMap keeps the output order stable between calls
+    let mut tokens: BTreeMap<String, MintBalance> = BTreeMap::new();
+
+    for program_id in [spl_token::id(), spl_token_2022::id()] {
+        let keyed_accounts = client.get_token_accounts_by_owner(
+            &owner_pubkey,
+            TokenAccountsFilter::ProgramId(program_id),
+        )?;
+
+        for keyed_account in keyed_accounts {
+            // The RPC returns jsonParsed data for token accounts
+            let UiAccountData::Json(parsed_account) = keyed_account.account.data else {
+                continue;
+            };
+
+            let Ok(TokenAccountType::Account(token_account)) =
+                serde_json::from_value::<TokenAccountType>(parsed_account.parsed)
+            else {
+                continue;
+            };
+
+            let (Ok(mint_pubkey), Ok(amount)) = (
+                token_account.mint.parse::<Pubkey>(),
+                token_account.token_amount.amount.parse::<u64>(),
+            ) else {
+                continue;
+            };
+
+            let decimals = token_account.token_amount.decimals;
+            let ata = get_associated_token_address_with_program_id(
+                &owner_pubkey,
+                &mint_pubkey,
+                &program_id,
+            );
+
+            let balance = TokenAccountBalance {
+                is_ata: keyed_account.pubkey == ata.to_string(),
+                address: keyed_account.pubkey,
+                amount,
+                ui_amount: format_ui_amount(amount, decimals),
+                is_frozen: token_account.state == UiAccountState::Frozen,
+                delegate: token_account.delegate,
+                delegated_amount: token_account
+                    .delegated_amount
+                    .and_then(|delegated| delegated.amount.parse::<u64>().ok())
+                    .unwrap_or(0),
+            };
+
+            let entry = tokens
+                .entry(token_account.mint.clone())
+                .or_insert_with(|| MintBalance {
+                    mint: token_account.mint,
+                    program_id: program_id.to_string(),
+                    decimals,
+                    amount: 0,
+                    ui_amount: String::new(),
+                    accounts: Vec::new(),
+                });
+
+            entry.amount = entry.amount.saturating_add(amount);
+            entry.accounts.push(balance);
+        }
+    }
+
+    let tokens: Vec<MintBalance> = tokens
+        .into_values()
+        .map(|mut balance| {
+            balance.ui_amount = format_ui_amount(balance.amount, balance.decimals);
+            balance
+        })
+        .collect();
+
+    info!(
+        "Wallet {} holds {} lamports and {} token mints",
+        owner_pubkey,
+        lamports,
+        tokens.len()
+    );
+
+    let response = WalletBalancesResponse {
+        address: owner_pubkey.to_string(),
+        lamports,
+        sol: format_ui_amount(lamports, SOL_DECIMALS),
+        tokens,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Wallet balances fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+// Decimal string for a signed amount in base units
+fn format_signed_ui_amount(amount: i128, decimals: u8) -> String {
+    let formatted = format_ui_amount(amount.unsigned_abs() as u64, decimals);
+    if amount < 0 {
+        format!("-{formatted}")
+    } else {
+        formatted
+    }
+}
+
+fn info_string(info: &serde_json::Value, field: &str) -> Option<String> {
+    info.get(field)?.as_str().map(str::to_string)
+}
+
+fn info_amount(info: &serde_json::Value) -> Option<u64> {
+    // Token amounts are strings; lamports are numbers
+    info.get("tokenAmount")
+        .and_then(|token_amount| token_amount.get("amount"))
+        .or_else(|| info.get("amount"))
+        .or_else(|| info.get("lamports"))
+        .and_then(|amount| {
+            amount
+                .as_u64()
+                .or_else(|| amount.as_str().and_then(|amount| amount.parse().ok()))
+        })
+}
+
+// Maps a parsed instruction to a feed action; None for instructions the feed ignores
+fn decode_action(
+    instruction: &ParsedInstruction,
+    account_mints: &HashMap<String, String>,
+) -> Option<WalletAction> {
+    let parsed: ParsedInstructionEnum = serde_json::from_value(instruction.parsed.clone()).ok()?;
+    let info = &parsed.info;
+    let program_id = instruction.program_id.parse::<Pubkey>().ok()?;
+
+    let (kind, source, destination) = if program_id == system_program::id() {
+        match parsed.instruction_type.as_str() {
+            "transfer" | "transferWithSeed" => ("transfer", "source", Some("destination")),
+            _ => return None,
+        }
+    } else if program_id == spl_token::id() || program_id == spl_token_2022::id() {
+        match parsed.instruction_type.as_str() {
+            "transfer" | "transferChecked" => ("transfer", "source", Some("destination")),
+            "mintTo" | "mintToChecked" => ("mint", "mint", Some("account")),
+            "burn" | "burnChecked" => ("burn", "account", None),
+            _ => return None,
+        }
+    } else if program_id == stake::program::id() {
+        match parsed.instruction_type.as_str() {
+            "delegate" => ("stake", "stakeAccount", Some("voteAccount")),
+            "deactivate" => ("stake", "stakeAccount", None),
+            "withdraw" => ("stake", "stakeAccount", Some("destination")),
+            "split" => ("stake", "stakeAccount", Some("newSplitAccount")),
+            "merge" => ("stake", "source", Some("destination")),
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+
+    // Token transfers name the mint only when checked; otherwise look it up by account
+    let mint = info_string(info, "mint").or_else(|| {
+        (kind == "transfer" && program_id != system_program::id())
+            .then(|| info_string(info, "source"))
+            .flatten()
+            .and_then(|source| account_mints.get(&source).cloned())
+    });
+
+    Some(WalletAction {
+        kind: kind.to_string(),
+        instruction: parsed.instruction_type.clone(),
+        program_id: instruction.program_id.clone(),
+        source: info_string(info, source),
+        destination: destination.and_then(|field| info_string(info, field)),
+        authority: info_string(info, "authority")
+            .or_else(|| info_string(info, "multisigAuthority"))
+            .or_else(|| info_string(info, "mintAuthority"))
+            .or_else(|| info_string(info, "multisigMintAuthority"))
+            .or_else(|| info_string(info, "stakeAuthority"))
+            .or_else(|| info_string(info, "withdrawAuthority")),
+        mint,
+        amount: info_amount(info),
+    })
+}
+
+fn summarize_transaction(
+    address: &str,
+    transaction: EncodedConfirmedTransactionWithStatusMeta,
+) -> Option<WalletHistoryEntry> {
+    let meta = transaction.transaction.meta?;
+    let EncodedTransaction::Json(ui_transaction) = transaction.transaction.transaction else {
+        return None;
+    };
+    let UiMessage::Parsed(message) = ui_transaction.message else {
+        return None;
+    };
+
+    let account_keys: Vec<String> = message
+        .account_keys
+        .iter()
+        .map(|account| account.pubkey.clone())
+        .collect();
+
+    let sol_change = account_keys
+        .iter()
+        .position(|key| key == address)
+        .map(|index| {
+            let pre = meta.pre_balances.get(index).copied().unwrap_or_default();
+            let post = meta.post_balances.get(index).copied().unwrap_or_default();
+            post as i64 - pre as i64
+        })
+        .unwrap_or_default();
+
+    // Token balances keyed by account index, for accounts owned by the address
+    let pre_token_balances: Vec<UiTransactionTokenBalance> =
+        Option::from(meta.pre_token_balances).unwrap_or_default();
+    let post_token_balances: Vec<UiTransactionTokenBalance> =
+        Option::from(meta.post_token_balances).unwrap_or_default();
+
+    let mut account_mints: HashMap<String, String> = HashMap::new();
+    let mut owned_accounts: HashSet<String> = HashSet::from([address.to_string()]);
+    // mint -> (decimals, change)
+    let mut token_changes: BTreeMap<String, (u8, i128)> = BTreeMap::new();
+
+    for (balances, sign) in [(&pre_token_balances, -1i128), (&post_token_balances, 1)] {
+        for balance in balances {
+            let Some(account) = account_keys.get(balance.account_index as usize) else {
+                continue;
+            };
+            account_mints.insert(account.clone(), balance.mint.clone());
+
+            if Option::<&String>::from(balance.owner.as_ref()).map(String::as_str) != Some(address)
+            {
+                continue;
+            }
+            owned_accounts.insert(account.clone());
+
+            let amount = balance
+                .ui_token_amount
+                .amount
+                .parse::<u64>()
+                .unwrap_or_default() as i128;
+            let entry = token_changes
+                .entry(balance.mint.clone())
+                .or_insert((balance.ui_token_amount.decimals, 0));
+            entry.1 += sign * amount;
+        }
+    }
+
+    let inner_instructions: Vec<UiInnerInstructions> =
+        Option::from(meta.inner_instructions).unwrap_or_default();
+
+    // Top-level instructions followed by CPIs, keeping only actions touching the wallet
+    let actions: Vec<WalletAction> = message
+        .instructions
+        .iter()
+        .chain(
+            inner_instructions
+                .iter()
+                .flat_map(|inner| inner.instructions.iter()),
+        )
+        .filter_map(|instruction| match instruction {
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+                decode_action(parsed, &account_mints)
+            }
+            _ => None,
+        })
+        .filter(|action| {
+            [&action.source, &action.destination, &action.authority]
+                .into_iter()
+                .flatten()
+                .any(|account| owned_accounts.contains(account))
+        })
+        .collect();
+
+    Some(WalletHistoryEntry {
+        signature: ui_transaction
+            .signatures
+            .first()
+            .cloned()
+            .unwrap_or_default(),
+        slot: transaction.slot,
+        block_time: transaction.block_time,
+        status: if meta.err.is_some() {
+            "failed"
+        } else {
+            "success"
+        }
+        .to_string(),
+        error: meta.err.map(|e| e.to_string()),
+        fee: Some(meta.fee),
+        fee_payer: account_keys.first().cloned(),
+        sol_change: Some(sol_change),
+        sol_change_ui: Some(format_signed_ui_amount(sol_change as i128, SOL_DECIMALS)),
+        token_changes: token_changes
+            .into_iter()
+            .filter(|(_, (_, change))| *change != 0)
+            .map(|(mint, (decimals, change))| TokenChange {
+                mint,
+                decimals,
+                change: change.to_string(),
+                ui_change: format_signed_ui_amount(change, decimals),
+            })
+            .collect(),
+        actions,
+    })
+}
+
+async fn get_history(
+    Path(address): Path<String>,
+    Query(query): Query<WalletHistoryQuery>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "GET /wallet/{}/history - Query: {}",
+        address,
+        serde_json::to_string(&query).unwrap_or_default()
+    );
+
+    let owner_pubkey = address
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput("Invalid wallet address".to_string()))?;
+
+    let parse_cursor = |cursor: Option<&String>, name: &str| {
+        cursor
+            .map(|cursor| cursor.parse::<Signature>())
+            .transpose()
+            .map_err(|_| SolanaError::InvalidInput(format!("Invalid {name} signature")))
+    };
+    let before = parse_cursor(query.before.as_ref(), "before")?;
+    let until = parse_cursor(query.until.as_ref(), "until")?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    if limit == 0 || limit > MAX_HISTORY_LIMIT {
+        return Err(SolanaError::InvalidInput(format!(
+            "limit must be between 1 and {MAX_HISTORY_LIMIT}"
+        )));
+    }
+
+    let client = get_rpc_client();
+
+    let signatures = client.get_signatures_for_address_with_config(
+        &owner_pubkey,
+        GetConfirmedSignaturesForAddress2Config {
+            before,
+            until,
+            limit: Some(limit),
+            commitment: Some(CommitmentConfig::confirmed()),
+        },
+    )?;
+
+    let address = owner_pubkey.to_string();
+    let mut transactions = Vec::with_capacity(signatures.len());
+
+    for signature_info in &signatures {
+        let signature = signature_info
+            .signature
+            .parse::<Signature>()
+            .map_err(|_| SolanaError::InvalidInput("Invalid signature from RPC".to_string()))?;
+
+        let transaction = client
+            .get_transaction_with_config(
+                &signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::JsonParsed),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .map_err(|e| warn!("Failed to fetch transaction {}: {}", signature, e))
+            .ok();
+
+        // Pruned transactions and ones the node can no longer decode still appear in the feed
+        let entry = transaction
+            .and_then(|transaction| summarize_transaction(&address, transaction))
+            .unwrap_or_else(|| WalletHistoryEntry {
+                signature: signature_info.signature.clone(),
+                slot: signature_info.slot,
+                block_time: signature_info.block_time,
+                status: if signature_info.err.is_some() {
+                    "failed"
+                } else {
+                    "success"
+                }
+                .to_string(),
+                error: signature_info.err.as_ref().map(|e| e.to_string()),
+                fee: None,
+                fee_payer: None,
+                sol_change: None,
+                sol_change_ui: None,
+                token_changes: Vec::new(),
+                actions: Vec::new(),
+            });
+
+        transactions.push(entry);
+    }
+
+    // A full page means older transactions may remain
+    let next_before = (signatures.len() == limit)
+        .then(|| signatures.last().map(|info| info.signature.clone()))
+        .flatten();
+
+    info!(
+        "Wallet {} history: {} transactions",
+        owner_pubkey,
+        transactions.len()
+    );
+
+    let response = WalletHistoryResponse {
+        address,
+        transactions,
+        next_before,
+    };
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": response
+    });
+
+    info!("Response: 200 - Wallet history fetched successfully");
+
+    Ok(Json(json_response))
+}
@@ -0,0 +1,1112 @@
+use crate::modules::stream::{pubsub_url, UpstreamError, UPSTREAM_CLOSED};
+use crate::utils::errors::SolanaError;
+use crate::utils::pubkey::parse_pubkey;
+use crate::utils::solana_client::get_rpc_client;
+use crate::utils::storage::Collection;
+use axum::{extract::Path, routing::get, Json, Router};
+use chrono::Utc;
+use futures_util::{stream, FutureExt, StreamExt};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::{
+    RpcAccountInfoConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const WEBHOOKS: &str = "webhooks";
+const DEAD_LETTERS: &str = "webhook_dead_letters";
+// Events not yet delivered or dead-lettered, replayed on startup
+const PENDING_EVENTS: &str = "webhook_pending_events";
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 15;
+const DELIVERY_RETRY: RetryPolicy = RetryPolicy {
+    attempts: 5,
+    initial_backoff: Duration::from_secs(1),
+};
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const SIGNATURE_HEADER: &str = "x-webhook-signature";
+const TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+const ID_HEADER: &str = "x-webhook-id";
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateWebhookRequest {
+    pub address: Option<String>,
+    pub url: Option<String>,
+    // HMAC key for payload signatures; generated when omitted
+    #[serde(skip_serializing)]
+    pub secret: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: String,
+    pub address: String,
+    pub url: String,
+    pub secret: String,
+    // Last seen state; both are None until the first poll records a baseline
+    pub last_signature: Option<String>,
+    pub lamports: Option<u64>,
+    pub created_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct WebhookResponse {
+    pub id: String,
+    pub address: String,
+    pub url: String,
+    // Only returned when the webhook is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub last_signature: Option<String>,
+    pub lamports: Option<u64>,
+    pub created_at: i64,
+}
+
+// The JSON body POSTed to the webhook URL
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: String,
+    pub webhook_id: String,
+    pub address: String,
+    pub lamports: u64,
+    pub previous_lamports: u64,
+    // New signatures involving the address, oldest first
+    pub signatures: Vec<String>,
+    pub timestamp: i64,
+}
+
+// Delivery attempts before an event is dead-lettered; the backoff doubles after each failure
+#[derive(Clone, Copy)]
+struct RetryPolicy {
+    attempts: u32,
+    initial_backoff: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub event: WebhookEvent,
+    pub url: String,
+    pub attempts: u32,
+    pub last_error: String,
+    pub failed_at: i64,
+}
+
+impl WebhookResponse {
+    fn new(webhook: Webhook, include_secret: bool) -> Self {
+        Self {
+            id: webhook.id,
+            address: webhook.address,
+            url: webhook.url,
+            secret: include_secret.then_some(webhook.secret),
+            last_signature: webhook.last_signature,
+            lamports: webhook.lamports,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/webhooks", get(list_webhooks).post(create_webhook))
+        .route("/webhooks/:id", get(get_webhook).delete(delete_webhook))
+        .route("/webhooks/:id/dead-letters", get(get_dead_letters))
+}
+
+// Checks a watched address as soon as logsSubscribe/accountSubscribe report activity on it, and
+// polls every address each WEBHOOK_POLL_INTERVAL_SECS as the fallback when the websocket
+// endpoint is unavailable or a notification is missed. Delivers an event per change.
+pub fn spawn_webhook_poller() {
+    let interval = env::var("WEBHOOK_POLL_INTERVAL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .unwrap_or(DEFAULT_POLL_INTERVAL_SECS);
+
+    let (activity_sender, mut activity) = mpsc::unbounded_channel::<String>();
+
+    tokio::spawn(async move {
+        match tokio::task::spawn_blocking(pending_deliveries).await {
+            Ok(Ok(deliveries)) => {
+                if !deliveries.is_empty() {
+                    info!("Redelivering {} pending webhook events", deliveries.len());
+                }
+                for (webhook, event) in deliveries {
+                    tokio::spawn(deliver(webhook, event, DELIVERY_RETRY));
+                }
+            }
+            Ok(Err(e)) => warn!("Loading pending webhook events failed: {}", e),
+            Err(e) => warn!("Loading pending webhook events panicked: {}", e),
+        }
+
+        let mut watcher = AddressWatcher::new(activity_sender);
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+        loop {
+            // A tick checks every webhook; activity only the webhooks it was reported for
+            let only = tokio::select! {
+                _ = ticker.tick() => None,
+                Some(webhook_id) = activity.recv() => {
+                    let mut webhook_ids = HashSet::from([webhook_id]);
+                    while let Ok(webhook_id) = activity.try_recv() {
+                        webhook_ids.insert(webhook_id);
+                    }
+                    Some(webhook_ids)
+                }
+            };
+            let polled_all = only.is_none();
+
+            let polled = tokio::task::spawn_blocking(move || poll_webhooks(only.as_ref())).await;
+            if polled_all {
+                watcher.sync().await;
+            }
+
+            let events = match polled {
+                Ok(Ok(events)) => events,
+                Ok(Err(e)) => {
+                    warn!("Webhook poll failed: {}", e);
+                    continue;
+                }
+                Err(e) => {
+                    warn!("Webhook poll task panicked: {}", e);
+                    continue;
+                }
+            };
+
+            // Deliveries retry independently so a slow endpoint does not delay polling
+            for (webhook, event) in events {
+                tokio::spawn(deliver(webhook, event, DELIVERY_RETRY));
+            }
+        }
+    });
+}
+
+// Pairs each stored pending event with its webhook; events of deleted webhooks are dropped
+fn pending_deliveries() -> Result<Vec<(Webhook, WebhookEvent)>, SolanaError> {
+    let webhooks = Collection::open(WEBHOOKS)?;
+    let pending = Collection::open(PENDING_EVENTS)?;
+    let mut deliveries = Vec::new();
+
+    for event in pending.values::<WebhookEvent>()? {
+        match webhooks.get::<Webhook>(&event.webhook_id)? {
+            Some(webhook) => deliveries.push((webhook, event)),
+            None => {
+                pending.remove(&event.id)?;
+            }
+        }
+    }
+
+    Ok(deliveries)
+}
+
+// Subscriptions that report activity on watched addresses, kept in step with the stored webhooks
+struct AddressWatcher {
+    pubsub: Option<Arc<PubsubClient>>,
+    watches: HashMap<String, JoinHandle<UpstreamError>>,
+    activity: mpsc::UnboundedSender<String>,
+}
+
+impl AddressWatcher {
+    fn new(activity: mpsc::UnboundedSender<String>) -> Self {
+        Self {
+            pubsub: None,
+            watches: HashMap::new(),
+            activity,
+        }
+    }
+
+    // Stops watches of deleted webhooks and (re)starts missing ones; the poll covers any gaps
+    async fn sync(&mut self) {
+        let webhooks = match Collection::open(WEBHOOKS).and_then(|webhooks| webhooks.values()) {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                warn!("Loading webhooks for subscriptions failed: {}", e);
+                return;
+            }
+        };
+        let webhook_ids: HashSet<&str> = webhooks
+            .iter()
+            .map(|webhook: &Webhook| webhook.id.as_str())
+            .collect();
+
+        let mut connection_lost = false;
+        self.watches.retain(|webhook_id, watch| {
+            if !webhook_ids.contains(webhook_id.as_str()) {
+                watch.abort();
+                return false;
+            }
+
+            match watch.now_or_never() {
+                None => true,
+                Some(ended) => {
+                    let e = ended.unwrap_or_else(|e| UpstreamError::Connection(e.to_string()));
+                    warn!("Subscription for webhook {} ended: {}", webhook_id, e);
+                    connection_lost |= matches!(e, UpstreamError::Connection(_));
+                    false
+                }
+            }
+        });
+        if connection_lost {
+            self.pubsub = None;
+        }
+
+        let missing: Vec<&Webhook> = webhooks
+            .iter()
+            .filter(|webhook| !self.watches.contains_key(&webhook.id))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        let pubsub = match &self.pubsub {
+            Some(pubsub) => pubsub.clone(),
+            None => {
+                let url = pubsub_url();
+                let connected = tokio::time::timeout(CONNECT_TIMEOUT, PubsubClient::new(&url))
+                    .await
+                    .map_err(|e| e.to_string())
+                    .and_then(|connected| connected.map_err(|e| e.to_string()));
+                match connected {
+                    Ok(pubsub) => self.pubsub.insert(Arc::new(pubsub)).clone(),
+                    Err(e) => {
+                        warn!("Webhook subscriptions unavailable, polling only: {}", e);
+                        return;
+                    }
+                }
+            }
+        };
+
+        for webhook in missing {
+            let Ok(address) = webhook.address.parse::<Pubkey>() else {
+                continue;
+            };
+
+            let watch = tokio::spawn(watch_address(
+                pubsub.clone(),
+                webhook.id.clone(),
+                address,
+                self.activity.clone(),
+            ));
+            self.watches.insert(webhook.id.clone(), watch);
+        }
+    }
+}
+
+// Reports the webhook whenever a transaction mentions the address or its account changes
+async fn watch_address(
+    pubsub: Arc<PubsubClient>,
+    webhook_id: String,
+    address: Pubkey,
+    activity: mpsc::UnboundedSender<String>,
+) -> UpstreamError {
+    let (logs, logs_unsubscribe) = match pubsub
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![address.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(e) => return e.into(),
+    };
+
+    // Only the notification matters, so no account data is sent
+    let account_config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        data_slice: Some(UiDataSliceConfig {
+            offset: 0,
+            length: 0,
+        }),
+        commitment: Some(CommitmentConfig::confirmed()),
+        min_context_slot: None,
+    };
+    let (accounts, account_unsubscribe) = match pubsub
+        .account_subscribe(&address, Some(account_config))
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(e) => {
+            logs_unsubscribe().await;
+            return e.into();
+        }
+    };
+
+    let mut notifications = stream::select(logs.map(|_| ()), accounts.map(|_| ()));
+    while notifications.next().await.is_some() {
+        if activity.send(webhook_id.clone()).is_err() {
+            break;
+        }
+    }
+    drop(notifications);
+
+    logs_unsubscribe().await;
+    account_unsubscribe().await;
+
+    UpstreamError::Connection(UPSTREAM_CLOSED.to_string())
+}
+
+fn poll_webhooks(
+    only: Option<&HashSet<String>>,
+) -> Result<Vec<(Webhook, WebhookEvent)>, SolanaError> {
+    let webhooks = Collection::open(WEBHOOKS)?;
+    let pending = Collection::open(PENDING_EVENTS)?;
+    let client = get_rpc_client();
+    let mut events = Vec::new();
+
+    for mut webhook in webhooks.values::<Webhook>()? {
+        if only.is_some_and(|webhook_ids| !webhook_ids.contains(&webhook.id)) {
+            continue;
+        }
+
+        let event = match check_address(&client, &mut webhook) {
+            Ok(event) => event,
+            Err(e) => {
+                warn!("Failed to check webhook {}: {}", webhook.id, e);
+                continue;
+            }
+        };
+
+        // Skip webhooks deleted while this poll was running
+        if webhooks.get::<Webhook>(&webhook.id)?.is_none() {
+            continue;
+        }
+
+        // Stored before the new state so a restart redelivers the event instead of losing it
+        if let Some(event) = &event {
+            pending.insert(&event.id, event)?;
+        }
+        webhooks.insert(&webhook.id, &webhook)?;
+
+        if let Some(event) = event {
+            events.push((webhook, event));
+        }
+    }
+
+    Ok(events)
+}
+
+// Compares the address against the last seen state and returns an event when it changed
+fn check_address(
+    client: &RpcClient,
+    webhook: &mut Webhook,
+) -> Result<Option<WebhookEvent>, SolanaError> {
+    let address = parse_pubkey(Some(&webhook.address), "address")?;
+
+    let until = webhook
+        .last_signature
+        .as_ref()
+        .and_then(|signature| signature.parse::<Signature>().ok());
+
+    let lamports = client.get_balance(&address)?;
+    let signatures = client.get_signatures_for_address_with_config(
+        &address,
+        GetConfirmedSignaturesForAddress2Config {
+            until,
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        },
+    )?;
+
+    let previous_lamports = webhook.lamports;
+    webhook.lamports = Some(lamports);
+    if let Some(newest) = signatures.first() {
+        webhook.last_signature = Some(newest.signature.clone());
+    }
+
+    // The first poll only records a baseline
+    let Some(previous_lamports) = previous_lamports else {
+        return Ok(None);
+    };
+
+    if signatures.is_empty() && previous_lamports == lamports {
+        return Ok(None);
+    }
+
+    Ok(Some(WebhookEvent {
+        id: Uuid::new_v4().to_string(),
+        webhook_id: webhook.id.clone(),
+        address: webhook.address.clone(),
+        lamports,
+        previous_lamports,
+        signatures: signatures
+            .iter()
+            .rev()
+            .map(|signature_info| signature_info.signature.clone())
+            .collect(),
+        timestamp: Utc::now().timestamp(),
+    }))
+}
+
+// Hex HMAC-SHA256 over "{timestamp}.{body}"
+fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+// Re-checks the host on every attempt and pins the checked addresses, so neither a redirect
+// nor a later DNS change can send payloads to an internal address
+async fn delivery_client(url: &str) -> Result<reqwest::Client, String> {
+    let url = url::Url::parse(url).map_err(|e| e.to_string())?;
+    let addresses = resolve_webhook_host(&url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none());
+    if let (Some(url::Host::Domain(domain)), false) = (url.host(), addresses.is_empty()) {
+        builder = builder.resolve_to_addrs(domain, &addresses);
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+async fn deliver(webhook: Webhook, event: WebhookEvent, retry: RetryPolicy) {
+    let body = match serde_json::to_vec(&event) {
+        Ok(body) => body,
+        Err(e) => {
+            warn!("Failed to encode webhook event {}: {}", event.id, e);
+            return;
+        }
+    };
+
+    let mut backoff = retry.initial_backoff;
+    let mut last_error = String::new();
+
+    for attempt in 1..=retry.attempts {
+        // Deleting the webhook cancels the remaining attempts
+        if !webhook_exists(&webhook.id) {
+            info!(
+                "Dropped webhook event {} of deleted webhook {}",
+                event.id, webhook.id
+            );
+            remove_pending(&event.id);
+            return;
+        }
+
+        // Signed per attempt so receivers can reject stale timestamps
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_payload(&webhook.secret, timestamp, &body);
+
+        let result = match delivery_client(&webhook.url).await {
+            Ok(http) => http
+                .post(&webhook.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(ID_HEADER, &event.id)
+                .header(TIMESTAMP_HEADER, timestamp)
+                .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+                .body(body.clone())
+                .send()
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                info!(
+                    "Delivered webhook event {} to {} (attempt {})",
+                    event.id, webhook.url, attempt
+                );
+                remove_pending(&event.id);
+                return;
+            }
+            Ok(response) => last_error = format!("HTTP {}", response.status()),
+            Err(e) => last_error = e,
+        }
+
+        warn!(
+            "Webhook event {} delivery attempt {} failed: {}",
+            event.id, attempt, last_error
+        );
+
+        if attempt < retry.attempts {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+
+    // The webhook may have been deleted during the last backoff
+    if !webhook_exists(&webhook.id) {
+        remove_pending(&event.id);
+        return;
+    }
+
+    let dead_letter = DeadLetter {
+        event,
+        url: webhook.url,
+        attempts: retry.attempts,
+        last_error,
+        failed_at: Utc::now().timestamp(),
+    };
+
+    let result = Collection::open(DEAD_LETTERS)
+        .and_then(|dead_letters| dead_letters.insert(&dead_letter.event.id, &dead_letter));
+    match result {
+        Ok(()) => {
+            warn!(
+                "Webhook event {} moved to the dead-letter list",
+                dead_letter.event.id
+            );
+            remove_pending(&dead_letter.event.id);
+        }
+        // Left pending so the event is retried after a restart
+        Err(e) => warn!(
+            "Failed to store dead letter {}: {}",
+            dead_letter.event.id, e
+        ),
+    }
+}
+
+// Storage errors count as existing so a delivery is never dropped by mistake
+fn webhook_exists(webhook_id: &str) -> bool {
+    Collection::open(WEBHOOKS)
+        .and_then(|webhooks| webhooks.get::<Webhook>(webhook_id))
+        .map_or(true, |webhook| webhook.is_some())
+}
+
+fn remove_pending(event_id: &str) {
+    let result = Collection::open(PENDING_EVENTS).and_then(|pending| pending.remove(event_id));
+    if let Err(e) = result {
+        warn!("Failed to clear pending webhook event {}: {}", event_id, e);
+    }
+}
+
+// Loopback, private, link-local and other non-routable addresses, e.g. cloud metadata endpoints
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                || first == 0
+                // Carrier-grade NAT, 100.64.0.0/10
+                || (first == 100 && second & 0xc0 == 64)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_internal_ip(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // Unique local fc00::/7 and link-local fe80::/10
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80
+            }
+        },
+    }
+}
+
+// Resolves the host and rejects internal addresses; hosts listed in WEBHOOK_ALLOWED_HOSTS
+// are not checked and return no addresses
+async fn resolve_webhook_host(url: &url::Url) -> Result<Vec<SocketAddr>, SolanaError> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| SolanaError::InvalidInput("Webhook URL must have a host".to_string()))?;
+
+    let allowed = env::var("WEBHOOK_ALLOWED_HOSTS").unwrap_or_default();
+    if allowed
+        .split(',')
+        .any(|allowed| allowed.trim().eq_ignore_ascii_case(host))
+    {
+        return Ok(Vec::new());
+    }
+
+    let port = url.port_or_known_default().unwrap_or(80);
+    let addresses: Vec<SocketAddr> = match url.host() {
+        Some(url::Host::Ipv4(ip)) => vec![SocketAddr::new(IpAddr::V4(ip), port)],
+        Some(url::Host::Ipv6(ip)) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+        _ => tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|_| {
+                SolanaError::InvalidInput("Webhook host could not be resolved".to_string())
+            })?
+            .collect(),
+    };
+
+    if addresses.iter().any(|address| is_internal_ip(address.ip())) {
+        return Err(SolanaError::InvalidInput(
+            "Webhook URL must not point to a private or local address".to_string(),
+        ));
+    }
+
+    Ok(addresses)
+}
+
+fn find_webhook(webhooks: &Collection, webhook_id: &str) -> Result<Webhook, SolanaError> {
+    webhooks
+        .get(webhook_id)?
+        .ok_or_else(|| SolanaError::InvalidInput("Webhook not found".to_string()))
+}
+
+async fn create_webhook(
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!(
+        "POST /webhooks - Request: {}",
+        serde_json::to_string(&payload).unwrap_or_default()
+    );
+
+    let url = payload
+        .url
+        .as_ref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?;
+
+    let parsed_url = url::Url::parse(url)
+        .map_err(|_| SolanaError::InvalidInput("Invalid webhook URL".to_string()))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err(SolanaError::InvalidInput(
+            "Webhook URL must use http or https".to_string(),
+        ));
+    }
+    resolve_webhook_host(&parsed_url).await?;
+
+    // Parse public keys AFTER validation
+    let address = parse_pubkey(payload.address.as_ref(), "address")?;
+
+    let secret = match payload.secret.filter(|secret| !secret.is_empty()) {
+        Some(secret) => secret,
+        None => {
+            let mut bytes = [0u8; 32];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            hex::encode(bytes)
+        }
+    };
+
+    let webhook = Webhook {
+        id: Uuid::new_v4().to_string(),
+        address: address.to_string(),
+        url: parsed_url.to_string(),
+        secret,
+        last_signature: None,
+        lamports: None,
+        created_at: Utc::now().timestamp(),
+    };
+
+    Collection::open(WEBHOOKS)?.insert(&webhook.id, &webhook)?;
+
+    info!(
+        "Registered webhook {} for {} -> {}",
+        webhook.id, webhook.address, webhook.url
+    );
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": WebhookResponse::new(webhook, true)
+    });
+
+    info!("Response: 200 - Webhook created successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn list_webhooks() -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /webhooks");
+
+    let webhooks: Vec<WebhookResponse> = Collection::open(WEBHOOKS)?
+        .values::<Webhook>()?
+        .into_iter()
+        .map(|webhook| WebhookResponse::new(webhook, false))
+        .collect();
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": webhooks
+    });
+
+    info!("Response: 200 - Webhooks listed successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_webhook(
+    Path(webhook_id): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /webhooks/{}", webhook_id);
+
+    let webhook = find_webhook(&Collection::open(WEBHOOKS)?, &webhook_id)?;
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": WebhookResponse::new(webhook, false)
+    });
+
+    info!("Response: 200 - Webhook fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn delete_webhook(
+    Path(webhook_id): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("DELETE /webhooks/{}", webhook_id);
+
+    if !Collection::open(WEBHOOKS)?.remove(&webhook_id)? {
+        return Err(SolanaError::InvalidInput("Webhook not found".to_string()));
+    }
+
+    // In-flight deliveries notice the deletion before their next attempt
+    let pending = Collection::open(PENDING_EVENTS)?;
+    for event in pending.values::<WebhookEvent>()? {
+        if event.webhook_id == webhook_id {
+            pending.remove(&event.id)?;
+        }
+    }
+
+    let dead_letters = Collection::open(DEAD_LETTERS)?;
+    for dead_letter in dead_letters.values::<DeadLetter>()? {
+        if dead_letter.event.webhook_id == webhook_id {
+            dead_letters.remove(&dead_letter.event.id)?;
+        }
+    }
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": { "id": webhook_id }
+    });
+
+    info!("Response: 200 - Webhook deleted successfully");
+
+    Ok(Json(json_response))
+}
+
+async fn get_dead_letters(
+    Path(webhook_id): Path<String>,
+) -> Result<Json<serde_json::Value>, SolanaError> {
+    info!("GET /webhooks/{}/dead-letters", webhook_id);
+
+    find_webhook(&Collection::open(WEBHOOKS)?, &webhook_id)?;
+
+    let mut dead_letters: Vec<DeadLetter> = Collection::open(DEAD_LETTERS)?
+        .values::<DeadLetter>()?
+        .into_iter()
+        .filter(|dead_letter| dead_letter.event.webhook_id == webhook_id)
+        .collect();
+    dead_letters.sort_by_key(|dead_letter| dead_letter.failed_at);
+
+    let json_response = serde_json::json!({
+        "success": true,
+        "data": dead_letters
+    });
+
+    info!("Response: 200 - Dead letters fetched successfully");
+
+    Ok(Json(json_response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::init_temp_storage;
+    use axum::extract::ws::{Message, WebSocketUpgrade};
+    use axum::{http::StatusCode, routing::post};
+    use solana_client::rpc_request::RpcRequest;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const ADDRESS: &str = "11111111111111111111111111111111";
+
+    fn webhook(url: &str, lamports: Option<u64>) -> Webhook {
+        Webhook {
+            id: Uuid::new_v4().to_string(),
+            address: ADDRESS.to_string(),
+            url: url.to_string(),
+            secret: "secret".to_string(),
+            last_signature: None,
+            lamports,
+            created_at: 0,
+        }
+    }
+
+    fn event(webhook: &Webhook) -> WebhookEvent {
+        WebhookEvent {
+            id: Uuid::new_v4().to_string(),
+            webhook_id: webhook.id.clone(),
+            address: webhook.address.clone(),
+            lamports: 2,
+            previous_lamports: 1,
+            signatures: Vec::new(),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn sign_payload_is_hex_hmac_of_timestamp_and_body() {
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(b"1700000000.{\"id\":1}");
+        let expected = hex::encode(mac.finalize().into_bytes());
+
+        let signature = sign_payload("secret", 1_700_000_000, b"{\"id\":1}");
+
+        assert_eq!(signature, expected);
+        assert_eq!(signature.len(), 64);
+        assert_ne!(
+            signature,
+            sign_payload("other", 1_700_000_000, b"{\"id\":1}")
+        );
+        assert_ne!(
+            signature,
+            sign_payload("secret", 1_700_000_001, b"{\"id\":1}")
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_retries_then_writes_dead_letter() {
+        init_temp_storage();
+        // The stub listens on loopback, which deliveries refuse unless allowed
+        env::set_var("WEBHOOK_ALLOWED_HOSTS", "127.0.0.1");
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let app = Router::new().route(
+            "/hook",
+            post(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { StatusCode::INTERNAL_SERVER_ERROR }
+            }),
+        );
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(app.into_make_service()),
+        );
+
+        let webhook = webhook(&format!("http://127.0.0.1:{port}/hook"), Some(1));
+        Collection::open(WEBHOOKS)
+            .unwrap()
+            .insert(&webhook.id, &webhook)
+            .unwrap();
+        let event = event(&webhook);
+        let pending = Collection::open(PENDING_EVENTS).unwrap();
+        pending.insert(&event.id, &event).unwrap();
+
+        let retry = RetryPolicy {
+            attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+        };
+        deliver(webhook.clone(), event.clone(), retry).await;
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        let dead_letter: DeadLetter = Collection::open(DEAD_LETTERS)
+            .unwrap()
+            .get(&event.id)
+            .unwrap()
+            .expect("dead letter stored");
+        assert_eq!(dead_letter.attempts, 3);
+        assert_eq!(dead_letter.url, webhook.url);
+        assert_eq!(dead_letter.last_error, "HTTP 500 Internal Server Error");
+        assert!(pending.get::<WebhookEvent>(&event.id).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn deliver_does_not_follow_redirects() {
+        init_temp_storage();
+        env::set_var("WEBHOOK_ALLOWED_HOSTS", "127.0.0.1");
+
+        let app = Router::new().route(
+            "/hook",
+            post(|| async {
+                axum::response::Redirect::temporary("http://169.254.169.254/latest/meta-data")
+            }),
+        );
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(app.into_make_service()),
+        );
+
+        let webhook = webhook(&format!("http://127.0.0.1:{port}/hook"), Some(1));
+        Collection::open(WEBHOOKS)
+            .unwrap()
+            .insert(&webhook.id, &webhook)
+            .unwrap();
+        let event = event(&webhook);
+        let retry = RetryPolicy {
+            attempts: 1,
+            initial_backoff: Duration::from_millis(1),
+        };
+        deliver(webhook, event.clone(), retry).await;
+
+        let dead_letter: DeadLetter = Collection::open(DEAD_LETTERS)
+            .unwrap()
+            .get(&event.id)
+            .unwrap()
+            .expect("dead letter stored");
+        assert_eq!(dead_letter.last_error, "HTTP 307 Temporary Redirect");
+    }
+
+    #[tokio::test]
+    async fn deliver_drops_events_of_deleted_webhooks() {
+        init_temp_storage();
+
+        // Never stored, as if deleted before delivery started
+        let webhook = webhook("https://example.com/hook", Some(1));
+        let event = event(&webhook);
+        let pending = Collection::open(PENDING_EVENTS).unwrap();
+        pending.insert(&event.id, &event).unwrap();
+
+        deliver(webhook, event.clone(), DELIVERY_RETRY).await;
+
+        assert!(pending.get::<WebhookEvent>(&event.id).unwrap().is_none());
+        let dead_letter: Option<DeadLetter> = Collection::open(DEAD_LETTERS)
+            .unwrap()
+            .get(&event.id)
+            .unwrap();
+        assert!(dead_letter.is_none());
+    }
+
+    #[tokio::test]
+    async fn internal_hosts_are_refused_at_delivery() {
+        let error = delivery_client("http://169.254.169.254/latest/meta-data")
+            .await
+            .unwrap_err();
+        assert!(error.contains("private or local address"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn watch_address_reports_log_notifications() {
+        // Acknowledges every subscription with its request id and reports one transaction
+        let app = Router::new().route(
+            "/",
+            get(|ws: WebSocketUpgrade| async {
+                ws.on_upgrade(|mut socket| async move {
+                    while let Some(Ok(Message::Text(text))) = socket.recv().await {
+                        let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                        let id = request["id"].clone();
+                        let response =
+                            serde_json::json!({ "jsonrpc": "2.0", "result": id, "id": id });
+                        socket
+                            .send(Message::Text(response.to_string()))
+                            .await
+                            .unwrap();
+
+                        if request["method"] == "logsSubscribe" {
+                            let notification = serde_json::json!({
+                                "jsonrpc": "2.0",
+                                "method": "logsNotification",
+                                "params": {
+                                    "result": {
+                                        "context": { "slot": 1 },
+                                        "value": { "signature": "sig", "err": null, "logs": [] }
+                                    },
+                                    "subscription": id
+                                }
+                            });
+                            socket
+                                .send(Message::Text(notification.to_string()))
+                                .await
+                                .unwrap();
+                        }
+                    }
+                })
+            }),
+        );
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(app.into_make_service()),
+        );
+
+        let pubsub = PubsubClient::new(&format!("ws://127.0.0.1:{port}"))
+            .await
+            .unwrap();
+        let (sender, mut activity) = mpsc::unbounded_channel();
+        let watch = tokio::spawn(watch_address(
+            Arc::new(pubsub),
+            "webhook".to_string(),
+            ADDRESS.parse().unwrap(),
+            sender,
+        ));
+
+        let webhook_id = tokio::time::timeout(Duration::from_secs(5), activity.recv())
+            .await
+            .expect("activity reported")
+            .unwrap();
+        assert_eq!(webhook_id, "webhook");
+        watch.abort();
+    }
+
+    #[test]
+    fn check_address_reports_new_signatures_and_balance_change() {
+        // The mock returns a balance of 50 and one signature by default
+        let client = RpcClient::new_mock_with_mocks("succeeds".to_string(), HashMap::new());
+        let mut webhook = webhook("https://example.com/hook", Some(10));
+
+        let event = check_address(&client, &mut webhook)
+            .unwrap()
+            .expect("event for the change");
+
+        assert_eq!(event.webhook_id, webhook.id);
+        assert_eq!(event.previous_lamports, 10);
+        assert_eq!(event.lamports, 50);
+        assert_eq!(event.signatures.len(), 1);
+        assert_eq!(webhook.lamports, Some(50));
+        assert_eq!(webhook.last_signature.as_ref(), event.signatures.first());
+    }
+
+    #[test]
+    fn check_address_returns_none_without_changes() {
+        let mocks = HashMap::from([
+            (
+                RpcRequest::GetBalance,
+                serde_json::json!({ "context": { "slot": 1 }, "value": 10 }),
+            ),
+            (RpcRequest::GetSignaturesForAddress, serde_json::json!([])),
+        ]);
+        let client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+        let mut webhook = webhook("https://example.com/hook", Some(10));
+
+        assert!(check_address(&client, &mut webhook).unwrap().is_none());
+        assert_eq!(webhook.lamports, Some(10));
+        assert!(webhook.last_signature.is_none());
+    }
+
+    #[test]
+    fn internal_addresses_are_rejected() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "169.254.169.254",
+            "100.64.0.1",
+            "::1",
+            "fd00::1",
+        ] {
+            assert!(is_internal_ip(ip.parse().unwrap()), "{ip}");
+        }
+        assert!(!is_internal_ip("8.8.8.8".parse().unwrap()));
+    }
+}
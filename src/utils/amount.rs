@@ -0,0 +1,193 @@
+use crate::utils::errors::SolanaError;
+use solana_account_decoder::parse_token::real_number_string_trimmed;
+
+pub const SOL_DECIMALS: u8 = 9;
+
+// Converts a decimal string such as "1.25" into base units without going through f64
+pub fn parse_ui_amount(ui_amount: &str, decimals: u8) -> Result<u64, SolanaError> {
+    let ui_amount = ui_amount.trim();
+
+    let (whole, fraction) = ui_amount.split_once('.').unwrap_or((ui_amount, ""));
+
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(SolanaError::InvalidInput(format!(
+            "Invalid uiAmount: {ui_amount}"
+        )));
+    }
+
+    if fraction.len() > decimals as usize {
+        return Err(SolanaError::InvalidInput(format!(
+            "uiAmount has more than {decimals} fractional digits"
+        )));
+    }
+
+    let overflow = || SolanaError::InvalidInput("uiAmount is too large".to_string());
+
+    let scale = 10u64.checked_pow(decimals as u32).ok_or_else(overflow)?;
+    let whole_units = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<u64>().map_err(|_| overflow())?
+    };
+
+    // Right-pad the fraction to the full number of decimals
+    let fraction_units = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<width$}", width = decimals as usize)
+            .parse::<u64>()
+            .map_err(|_| overflow())?
+    };
+
+    whole_units
+        .checked_mul(scale)
+        .and_then(|units| units.checked_add(fraction_units))
+        .ok_or_else(overflow)
+}
+
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    real_number_string_trimmed(amount, decimals)
+}
+
+// Accepts exactly one of a raw `amount` or a `uiAmount` string and returns base units
+pub fn resolve_amount(
+    amount: Option<u64>,
+    ui_amount: Option<&String>,
+    decimals: u8,
+) -> Result<u64, SolanaError> {
+    let ui_amount = ui_amount.filter(|s| !s.trim().is_empty());
+
+    let amount = match (amount, ui_amount) {
+        (Some(_), Some(_)) => {
+            return Err(SolanaError::InvalidInput(
+                "Provide either amount or uiAmount, not both".to_string(),
+            ))
+        }
+        // Matches the original raw-amount validation of the token endpoints
+        (Some(0), None) => return Err(SolanaError::MissingFields),
+        (Some(amount), None) => amount,
+        (None, Some(ui_amount)) => parse_ui_amount(ui_amount, decimals)?,
+        (None, None) => return Err(SolanaError::MissingFields),
+    };
+
+    if amount == 0 {
+        return Err(SolanaError::InvalidInput(
+            "Amount must be greater than zero".to_string(),
+        ));
+    }
+
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ui_amount_scales_to_base_units() {
+        assert_eq!(parse_ui_amount("1.25", 9).unwrap(), 1_250_000_000);
+        assert_eq!(parse_ui_amount(" 0.000000001 ", 9).unwrap(), 1);
+        assert_eq!(parse_ui_amount(".5", 2).unwrap(), 50);
+        assert_eq!(parse_ui_amount("5.", 2).unwrap(), 500);
+        assert_eq!(parse_ui_amount("007", 0).unwrap(), 7);
+    }
+
+    #[test]
+    fn parse_ui_amount_never_rounds_extra_digits() {
+        for (ui_amount, decimals) in [("1.234", 2), ("0.1", 0), ("0.0000000001", 9)] {
+            assert!(
+                matches!(
+                    parse_ui_amount(ui_amount, decimals),
+                    Err(SolanaError::InvalidInput(message)) if message.contains("fractional digits")
+                ),
+                "{ui_amount} with {decimals} decimals"
+            );
+        }
+        // Trailing zeros still count as digits
+        assert!(parse_ui_amount("1.000", 2).is_err());
+    }
+
+    #[test]
+    fn parse_ui_amount_rejects_malformed_input() {
+        for ui_amount in ["", ".", "abc", "-1", "+1", "1e5", "1.2.3", "1,5", "1 000"] {
+            assert!(parse_ui_amount(ui_amount, 9).is_err(), "{ui_amount:?}");
+        }
+    }
+
+    #[test]
+    fn parse_ui_amount_detects_overflow() {
+        assert_eq!(
+            parse_ui_amount("18446744073709551615", 0).unwrap(),
+            u64::MAX
+        );
+        assert_eq!(
+            parse_ui_amount("18446744073709.551615", 6).unwrap(),
+            u64::MAX
+        );
+
+        for (ui_amount, decimals) in [
+            ("18446744073709551616", 0),
+            ("18446744073709.551616", 6),
+            ("18446744073710", 6),
+            ("1", 20),
+        ] {
+            assert!(
+                matches!(
+                    parse_ui_amount(ui_amount, decimals),
+                    Err(SolanaError::InvalidInput(message)) if message == "uiAmount is too large"
+                ),
+                "{ui_amount} with {decimals} decimals"
+            );
+        }
+    }
+
+    #[test]
+    fn format_ui_amount_trims_trailing_zeros() {
+        assert_eq!(format_ui_amount(1_250_000_000, 9), "1.25");
+        assert_eq!(format_ui_amount(1, 9), "0.000000001");
+        assert_eq!(format_ui_amount(500, 2), "5");
+        assert_eq!(format_ui_amount(0, 6), "0");
+    }
+
+    #[test]
+    fn resolve_amount_takes_exactly_one_amount() {
+        let ui_amount = "1.5".to_string();
+        let blank = "  ".to_string();
+
+        assert_eq!(resolve_amount(Some(7), None, 6).unwrap(), 7);
+        assert_eq!(
+            resolve_amount(None, Some(&ui_amount), 6).unwrap(),
+            1_500_000
+        );
+        // A blank uiAmount counts as missing
+        assert_eq!(resolve_amount(Some(7), Some(&blank), 6).unwrap(), 7);
+
+        assert!(matches!(
+            resolve_amount(Some(7), Some(&ui_amount), 6),
+            Err(SolanaError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            resolve_amount(None, None, 6),
+            Err(SolanaError::MissingFields)
+        ));
+        assert!(matches!(
+            resolve_amount(None, Some(&blank), 6),
+            Err(SolanaError::MissingFields)
+        ));
+    }
+
+    #[test]
+    fn resolve_amount_rejects_zero() {
+        let zero = "0.000".to_string();
+
+        assert!(matches!(
+            resolve_amount(Some(0), None, 6),
+            Err(SolanaError::MissingFields)
+        ));
+        assert!(matches!(
+            resolve_amount(None, Some(&zero), 6),
+            Err(SolanaError::InvalidInput(message)) if message == "Amount must be greater than zero"
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const DEFAULT_TTL_SECS: u64 = 30;
+
+// Small in-memory cache for RPC results that are expensive but change slowly
+#[derive(Clone)]
+pub struct TtlCache<V> {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, (Instant, V)>>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // TTL from RPC_CACHE_TTL_SECS, defaulting to 30 seconds
+    pub fn from_env() -> Self {
+        let secs = env::var("RPC_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(DEFAULT_TTL_SECS);
+
+        Self::new(Duration::from_secs(secs))
+    }
+
+    pub fn get(&self, key: &str) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(key) {
+            Some((stored_at, value)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: &str, value: V) {
+        let mut entries = self.entries.lock().unwrap();
+
+        // Drop expired entries so per-address keys do not accumulate
+        entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+        entries.insert(key.to_string(), (Instant::now(), value));
+    }
+
+    // Returns the cached value or computes, stores and returns a fresh one
+    pub fn get_or_try_insert<E>(
+        &self,
+        key: &str,
+        fetch: impl FnOnce() -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.get(key) {
+            return Ok(value);
+        }
+
+        let value = fetch()?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::policy::PolicyViolation;
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -10,7 +11,7 @@ use tracing::info;
 #[derive(Error, Debug)]
 pub enum SolanaError {
     #[error("Client error: {0}")]
-    ClientError(#[from] solana_client::client_error::ClientError),
+    ClientError(Box<solana_client::client_error::ClientError>),
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
@@ -20,6 +21,21 @@ pub enum SolanaError {
 
     #[error("Token error: {0}")]
     TokenError(String),
+
+    #[error("Authority mismatch: {0}")]
+    AuthorityMismatch(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error("Policy violation ({}): {}", .0.rule, .0.reason)]
+    PolicyViolation(PolicyViolation),
+}
+
+impl From<solana_client::client_error::ClientError> for SolanaError {
+    fn from(error: solana_client::client_error::ClientError) -> Self {
+        SolanaError::ClientError(Box::new(error))
+    }
 }
 
 impl IntoResponse for SolanaError {
@@ -28,15 +44,25 @@ impl IntoResponse for SolanaError {
             SolanaError::MissingFields => (StatusCode::BAD_REQUEST, self.to_string()),
             SolanaError::InvalidInput(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             SolanaError::TokenError(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            SolanaError::AuthorityMismatch(_) => (StatusCode::FORBIDDEN, self.to_string()),
             SolanaError::ClientError(_) => (StatusCode::BAD_GATEWAY, self.to_string()),
+            SolanaError::StorageError(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
+            SolanaError::PolicyViolation(_) => (StatusCode::FORBIDDEN, self.to_string()),
         };
 
         info!("Response: {} - {}", status.as_u16(), error_message);
 
-        let body = Json(json!({
+        let mut body = json!({
             "success": false,
             "error": error_message
-        }));
+        });
+
+        // Tell the caller which policy rule blocked the request
+        if let SolanaError::PolicyViolation(violation) = &self {
+            body["policy"] = json!(violation);
+        }
+
+        let body = Json(body);
 
         (status, body).into_response()
     }
@@ -1,2 +1,7 @@
+pub mod amount;
+pub mod cache;
 pub mod errors;
+pub mod policy;
+pub mod pubkey;
 pub mod solana_client;
+pub mod storage;
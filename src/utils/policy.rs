@@ -0,0 +1,592 @@
+use crate::utils::errors::SolanaError;
+use crate::utils::storage::Collection;
+use axum::http::HeaderMap;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::{env, fs};
+use tracing::info;
+
+const API_KEY_HEADER: &str = "x-api-key";
+// Shared velocity bucket for requests whose API key is not listed in the config
+const ANONYMOUS_KEY: &str = "anonymous";
+const VELOCITY_USAGE: &str = "velocity_usage";
+// Asset key used for SOL in velocity limits; tokens use the mint address
+const SOL_ASSET: &str = "SOL";
+
+static POLICY: OnceLock<Policy> = OnceLock::new();
+
+#[derive(Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EndpointPolicy {
+    pub min_lamports: Option<u64>,
+    pub max_lamports: Option<u64>,
+    pub max_token_amount: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MintPolicy {
+    pub max_amount: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RecipientPolicy {
+    // When set, only these recipients are allowed
+    pub allow: Option<HashSet<String>>,
+    #[serde(default)]
+    pub deny: HashSet<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct VelocityPolicy {
+    // Daily limits per asset ("SOL" or a mint address) for the shared anonymous bucket
+    #[serde(default)]
+    pub default: HashMap<String, u64>,
+    // Keys with their own bucket and per-asset overrides, merged over the defaults
+    #[serde(default)]
+    pub api_keys: HashMap<String, HashMap<String, u64>>,
+}
+
+// Amount of one asset used by one bucket on `day` (YYYY-MM-DD, UTC)
+#[derive(Serialize, Deserialize)]
+struct VelocityUsage {
+    day: String,
+    used: u64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    // Keyed by route, e.g. "/send/sol"
+    #[serde(default)]
+    pub endpoints: HashMap<String, EndpointPolicy>,
+    // Keyed by mint address
+    #[serde(default)]
+    pub mints: HashMap<String, MintPolicy>,
+    #[serde(default)]
+    pub recipients: RecipientPolicy,
+    #[serde(default)]
+    pub velocity: VelocityPolicy,
+}
+
+// The rule that blocked a request, returned to the caller with the error
+#[derive(Debug, Serialize)]
+pub struct PolicyViolation {
+    pub rule: String,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<u64>,
+}
+
+// A single movement of funds to evaluate; `mint` is None for SOL
+pub struct Transfer {
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+pub struct Policy {
+    config: PolicyConfig,
+    // Serializes the check-then-record of velocity usage, which is kept in storage
+    usage_lock: Mutex<()>,
+}
+
+impl Policy {
+    pub fn new(mut config: PolicyConfig) -> Self {
+        // Built-in limits apply unless the config overrides them
+        config
+            .endpoints
+            .entry("/send/sol".to_string())
+            .or_insert(EndpointPolicy {
+                min_lamports: Some(1),
+                max_lamports: Some(100_000_000_000), // 100 SOL
+                max_token_amount: None,
+            });
+        config
+            .endpoints
+            .entry("/send/token".to_string())
+            .or_insert(EndpointPolicy {
+                max_token_amount: Some(u64::MAX / 2),
+                ..Default::default()
+            });
+
+        Self {
+            config,
+            usage_lock: Mutex::new(()),
+        }
+    }
+
+    // Static rules: recipient lists, endpoint limits and mint limits
+    pub fn check_transfer(
+        &self,
+        endpoint: &str,
+        transfer: &Transfer,
+    ) -> Result<(), PolicyViolation> {
+        let recipients = &self.config.recipients;
+        let recipient = transfer.recipient.to_string();
+
+        if recipients.deny.contains(&recipient) {
+            return Err(PolicyViolation {
+                rule: "recipients.deny".to_string(),
+                reason: format!("Recipient {} is on the deny list", transfer.recipient),
+                limit: None,
+                requested: None,
+            });
+        }
+
+        if let Some(allow) = &recipients.allow {
+            if !allow.contains(&recipient) {
+                return Err(PolicyViolation {
+                    rule: "recipients.allow".to_string(),
+                    reason: format!("Recipient {} is not on the allow list", transfer.recipient),
+                    limit: None,
+                    requested: None,
+                });
+            }
+        }
+
+        if let Some(endpoint_policy) = self.config.endpoints.get(endpoint) {
+            let (min, max, unit) = match transfer.mint {
+                None => (
+                    endpoint_policy.min_lamports,
+                    endpoint_policy.max_lamports,
+                    "lamports",
+                ),
+                Some(_) => (None, endpoint_policy.max_token_amount, "token units"),
+            };
+
+            if let Some(min) = min.filter(|&min| transfer.amount < min) {
+                return Err(PolicyViolation {
+                    rule: format!("endpoints.{endpoint}.minLamports"),
+                    reason: format!("Amount is below the minimum of {min} {unit} for {endpoint}"),
+                    limit: Some(min),
+                    requested: Some(transfer.amount),
+                });
+            }
+
+            if let Some(max) = max.filter(|&max| transfer.amount > max) {
+                let field = if transfer.mint.is_some() {
+                    "maxTokenAmount"
+                } else {
+                    "maxLamports"
+                };
+                return Err(PolicyViolation {
+                    rule: format!("endpoints.{endpoint}.{field}"),
+                    reason: format!("Amount exceeds the maximum of {max} {unit} for {endpoint}"),
+                    limit: Some(max),
+                    requested: Some(transfer.amount),
+                });
+            }
+        }
+
+        if let Some(mint) = transfer.mint {
+            if let Some(max) = self
+                .config
+                .mints
+                .get(&mint.to_string())
+                .and_then(|mint_policy| mint_policy.max_amount)
+                .filter(|&max| transfer.amount > max)
+            {
+                return Err(PolicyViolation {
+                    rule: format!("mints.{mint}.maxAmount"),
+                    reason: format!("Amount exceeds the maximum of {max} for mint {mint}"),
+                    limit: Some(max),
+                    requested: Some(transfer.amount),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Unlisted keys are chosen by the client, so they all share the anonymous bucket
+    fn velocity_bucket<'a>(&'a self, api_key: Option<&'a str>) -> &'a str {
+        api_key
+            .filter(|key| self.config.velocity.api_keys.contains_key(*key))
+            .unwrap_or(ANONYMOUS_KEY)
+    }
+
+    fn daily_limit(&self, bucket: &str, asset: &str) -> Option<u64> {
+        let velocity = &self.config.velocity;
+
+        velocity
+            .api_keys
+            .get(bucket)
+            .and_then(|limits| limits.get(asset))
+            .or_else(|| velocity.default.get(asset))
+            .copied()
+    }
+
+    // Checks every asset against the bucket's daily limit and only then records the usage
+    pub fn consume_velocity(
+        &self,
+        api_key: Option<&str>,
+        transfers: &[Transfer],
+    ) -> Result<(), SolanaError> {
+        let bucket = self.velocity_bucket(api_key);
+        let today = Utc::now().date_naive().to_string();
+
+        let mut totals: HashMap<String, u64> = HashMap::new();
+        for transfer in transfers {
+            let asset = transfer
+                .mint
+                .map(|mint| mint.to_string())
+                .unwrap_or_else(|| SOL_ASSET.to_string());
+            let total = totals.entry(asset).or_default();
+            *total = total.saturating_add(transfer.amount);
+        }
+
+        let limited: Vec<(String, u64, u64)> = totals
+            .into_iter()
+            .filter_map(|(asset, amount)| {
+                let limit = self.daily_limit(bucket, &asset)?;
+                Some((asset, amount, limit))
+            })
+            .collect();
+
+        if limited.is_empty() {
+            return Ok(());
+        }
+
+        let _guard = self.usage_lock.lock().unwrap();
+        let usage = Collection::open(VELOCITY_USAGE)?;
+
+        let mut updates = Vec::with_capacity(limited.len());
+        for (asset, amount, limit) in limited {
+            let key = format!("{bucket}/{asset}");
+            let used = usage
+                .get::<VelocityUsage>(&key)?
+                .filter(|usage| usage.day == today)
+                .map_or(0, |usage| usage.used);
+
+            if used.saturating_add(amount) > limit {
+                return Err(SolanaError::PolicyViolation(PolicyViolation {
+                    rule: format!("velocity.{asset}"),
+                    reason: format!(
+                        "Daily limit of {limit} for {asset} exceeded ({used} already used today)"
+                    ),
+                    limit: Some(limit),
+                    requested: Some(amount),
+                }));
+            }
+
+            updates.push((key, used.saturating_add(amount)));
+        }
+
+        for (key, used) in updates {
+            usage.insert(
+                &key,
+                &VelocityUsage {
+                    day: today.clone(),
+                    used,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Evaluates all rules for a request that moves funds
+    pub fn enforce(
+        &self,
+        endpoint: &str,
+        api_key: Option<&str>,
+        transfers: &[Transfer],
+    ) -> Result<(), SolanaError> {
+        for transfer in transfers {
+            self.check_transfer(endpoint, transfer)
+                .map_err(SolanaError::PolicyViolation)?;
+        }
+
+        self.consume_velocity(api_key, transfers)
+    }
+}
+
+// Loads the policy from POLICY_CONFIG (a JSON file); the built-in limits apply without one
+pub fn init_policy() -> Result<(), String> {
+    let config = match env::var("POLICY_CONFIG") {
+        Ok(path) => {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read policy config {path}: {e}"))?;
+            info!("Loaded policy config from {path}");
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("Invalid policy config {path}: {e}"))?
+        }
+        Err(_) => PolicyConfig::default(),
+    };
+
+    let addresses = config
+        .recipients
+        .allow
+        .iter()
+        .flatten()
+        .chain(&config.recipients.deny)
+        .chain(config.mints.keys());
+    for address in addresses {
+        address
+            .parse::<Pubkey>()
+            .map_err(|_| format!("Invalid address in policy config: {address}"))?;
+    }
+
+    POLICY
+        .set(Policy::new(config))
+        .map_err(|_| "Policy already initialized".to_string())
+}
+
+pub fn policy() -> &'static Policy {
+    POLICY.get_or_init(|| Policy::new(PolicyConfig::default()))
+}
+
+pub fn api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|key| !key.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::init_temp_storage;
+
+    fn policy_from(config: serde_json::Value) -> Policy {
+        Policy::new(serde_json::from_value(config).unwrap())
+    }
+
+    fn transfer(mint: Option<Pubkey>, amount: u64) -> Transfer {
+        Transfer {
+            recipient: Pubkey::new_unique(),
+            mint,
+            amount,
+        }
+    }
+
+    fn violated_rule(result: Result<(), SolanaError>) -> String {
+        match result {
+            Err(SolanaError::PolicyViolation(violation)) => violation.rule,
+            _ => panic!("expected a policy violation"),
+        }
+    }
+
+    #[test]
+    fn check_transfer_applies_recipient_lists() {
+        let allowed = Pubkey::new_unique();
+        let denied = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "recipients": {
+                "allow": [allowed.to_string(), denied.to_string()],
+                "deny": [denied.to_string()]
+            }
+        }));
+        let to = |recipient| Transfer {
+            recipient,
+            mint: None,
+            amount: 1,
+        };
+
+        assert!(policy.check_transfer("/send/sol", &to(allowed)).is_ok());
+        // The deny list wins over the allow list
+        assert_eq!(
+            policy
+                .check_transfer("/send/sol", &to(denied))
+                .unwrap_err()
+                .rule,
+            "recipients.deny"
+        );
+        assert_eq!(
+            policy
+                .check_transfer("/send/sol", &to(Pubkey::new_unique()))
+                .unwrap_err()
+                .rule,
+            "recipients.allow"
+        );
+    }
+
+    #[test]
+    fn check_transfer_applies_built_in_endpoint_limits() {
+        let policy = policy_from(serde_json::json!({}));
+
+        let violation = policy
+            .check_transfer("/send/sol", &transfer(None, 0))
+            .unwrap_err();
+        assert_eq!(violation.rule, "endpoints./send/sol.minLamports");
+        assert_eq!(violation.limit, Some(1));
+
+        assert!(policy
+            .check_transfer("/send/sol", &transfer(None, 100_000_000_000))
+            .is_ok());
+        let violation = policy
+            .check_transfer("/send/sol", &transfer(None, 100_000_000_001))
+            .unwrap_err();
+        assert_eq!(violation.rule, "endpoints./send/sol.maxLamports");
+        assert_eq!(violation.requested, Some(100_000_000_001));
+
+        // Endpoints without limits accept any amount
+        assert!(policy
+            .check_transfer("/pay/parse", &transfer(None, u64::MAX))
+            .is_ok());
+    }
+
+    #[test]
+    fn check_transfer_applies_token_and_mint_limits() {
+        let mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "endpoints": { "/send/batch": { "maxLamports": 10, "maxTokenAmount": 1000 } },
+            "mints": { mint.to_string(): { "maxAmount": 500 } }
+        }));
+
+        // Lamport limits do not apply to token transfers
+        assert!(policy
+            .check_transfer("/send/batch", &transfer(Some(mint), 500))
+            .is_ok());
+        assert_eq!(
+            policy
+                .check_transfer("/send/batch", &transfer(Some(mint), 501))
+                .unwrap_err()
+                .rule,
+            format!("mints.{mint}.maxAmount")
+        );
+        assert_eq!(
+            policy
+                .check_transfer("/send/batch", &transfer(Some(Pubkey::new_unique()), 1001))
+                .unwrap_err()
+                .rule,
+            "endpoints./send/batch.maxTokenAmount"
+        );
+        assert_eq!(
+            policy
+                .check_transfer("/send/batch", &transfer(None, 11))
+                .unwrap_err()
+                .rule,
+            "endpoints./send/batch.maxLamports"
+        );
+    }
+
+    #[test]
+    fn unlisted_api_keys_share_the_anonymous_bucket() {
+        init_temp_storage();
+        let mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "velocity": { "default": { mint.to_string(): 100 } }
+        }));
+
+        assert!(policy
+            .consume_velocity(Some("rotated-1"), &[transfer(Some(mint), 60)])
+            .is_ok());
+        assert_eq!(
+            violated_rule(policy.consume_velocity(Some("rotated-2"), &[transfer(Some(mint), 60)])),
+            format!("velocity.{mint}")
+        );
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 40)])
+            .is_ok());
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 1)])
+            .is_err());
+    }
+
+    #[test]
+    fn listed_api_keys_have_their_own_bucket() {
+        init_temp_storage();
+        let mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "velocity": {
+                "default": { mint.to_string(): 100, other_mint.to_string(): 10 },
+                "apiKeys": { "partner": { mint.to_string(): 1000 } }
+            }
+        }));
+
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 100)])
+            .is_ok());
+        // The override raises the limit; other assets fall back to the defaults
+        assert!(policy
+            .consume_velocity(Some("partner"), &[transfer(Some(mint), 1000)])
+            .is_ok());
+        assert!(policy
+            .consume_velocity(Some("partner"), &[transfer(Some(other_mint), 10)])
+            .is_ok());
+        assert!(policy
+            .consume_velocity(Some("partner"), &[transfer(Some(other_mint), 1)])
+            .is_err());
+    }
+
+    #[test]
+    fn velocity_is_all_or_nothing_per_request() {
+        init_temp_storage();
+        let mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "velocity": { "default": { mint.to_string(): 100, other_mint.to_string(): 100 } }
+        }));
+
+        // Transfers of one asset are summed before the check
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 60), transfer(Some(mint), 41)])
+            .is_err());
+        assert!(policy
+            .consume_velocity(
+                None,
+                &[transfer(Some(mint), 50), transfer(Some(other_mint), 101)]
+            )
+            .is_err());
+
+        // Nothing was recorded by the rejected requests
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 100)])
+            .is_ok());
+    }
+
+    #[test]
+    fn velocity_usage_resets_each_day() {
+        init_temp_storage();
+        let mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "velocity": { "default": { mint.to_string(): 100 } }
+        }));
+
+        Collection::open(VELOCITY_USAGE)
+            .unwrap()
+            .insert(
+                &format!("{ANONYMOUS_KEY}/{mint}"),
+                &VelocityUsage {
+                    day: "2000-01-01".to_string(),
+                    used: 100,
+                },
+            )
+            .unwrap();
+
+        assert!(policy
+            .consume_velocity(None, &[transfer(Some(mint), 100)])
+            .is_ok());
+    }
+
+    #[test]
+    fn enforce_does_not_consume_velocity_for_rejected_transfers() {
+        init_temp_storage();
+        let mint = Pubkey::new_unique();
+        let policy = policy_from(serde_json::json!({
+            "mints": { mint.to_string(): { "maxAmount": 80 } },
+            "velocity": { "default": { mint.to_string(): 100 } }
+        }));
+
+        assert_eq!(
+            violated_rule(policy.enforce("/send/token", None, &[transfer(Some(mint), 90)])),
+            format!("mints.{mint}.maxAmount")
+        );
+        assert!(policy
+            .enforce("/send/token", None, &[transfer(Some(mint), 80)])
+            .is_ok());
+        assert!(policy
+            .enforce("/send/token", None, &[transfer(Some(mint), 21)])
+            .is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::utils::errors::SolanaError;
+use solana_sdk::pubkey::Pubkey;
+
+// Parses a required address field; `name` is used in the error message
+pub fn parse_pubkey(value: Option<&String>, name: &str) -> Result<Pubkey, SolanaError> {
+    value
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(SolanaError::MissingFields)?
+        .parse::<Pubkey>()
+        .map_err(|_| SolanaError::InvalidInput(format!("Invalid {name} address")))
+}
+
+// Parses an optional address field, treating an empty string as absent
+pub fn parse_optional_pubkey(
+    value: Option<&String>,
+    name: &str,
+) -> Result<Option<Pubkey>, SolanaError> {
+    value
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| {
+            s.parse::<Pubkey>()
+                .map_err(|_| SolanaError::InvalidInput(format!("Invalid {name} address")))
+        })
+        .transpose()
+}
@@ -0,0 +1,84 @@
+use crate::utils::errors::SolanaError;
+use serde::{de::DeserializeOwned, Serialize};
+use std::env;
+use std::sync::OnceLock;
+use tracing::info;
+
+static DB: OnceLock<sled::Db> = OnceLock::new();
+
+// Opens the embedded database at STORAGE_PATH (default "data")
+pub fn init_storage() -> Result<(), String> {
+    let path = env::var("STORAGE_PATH").unwrap_or_else(|_| "data".to_string());
+
+    let db = sled::open(&path).map_err(|e| format!("Failed to open storage at {path}: {e}"))?;
+    info!("Opened storage at {path}");
+
+    DB.set(db)
+        .map_err(|_| "Storage already initialized".to_string())
+}
+
+// Temporary database shared by every test in the process, deleted when it is dropped
+#[cfg(test)]
+pub fn init_temp_storage() {
+    DB.get_or_init(|| {
+        sled::Config::new()
+            .temporary(true)
+            .open()
+            .expect("opening temporary storage failed")
+    });
+}
+
+fn storage_error(e: impl std::fmt::Display) -> SolanaError {
+    SolanaError::StorageError(e.to_string())
+}
+
+// A named collection of JSON records
+#[derive(Clone)]
+pub struct Collection {
+    tree: sled::Tree,
+}
+
+impl Collection {
+    pub fn open(name: &str) -> Result<Self, SolanaError> {
+        let db = DB
+            .get()
+            .ok_or_else(|| storage_error("Storage is not initialized"))?;
+
+        Ok(Self {
+            tree: db.open_tree(name).map_err(storage_error)?,
+        })
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, SolanaError> {
+        self.tree
+            .get(key)
+            .map_err(storage_error)?
+            .map(|value| serde_json::from_slice(&value).map_err(storage_error))
+            .transpose()
+    }
+
+    // Writes are flushed before returning so records survive a crash
+    pub fn insert<T: Serialize>(&self, key: &str, value: &T) -> Result<(), SolanaError> {
+        let bytes = serde_json::to_vec(value).map_err(storage_error)?;
+        self.tree.insert(key, bytes).map_err(storage_error)?;
+        self.tree.flush().map_err(storage_error)?;
+        Ok(())
+    }
+
+    pub fn remove(&self, key: &str) -> Result<bool, SolanaError> {
+        let removed = self.tree.remove(key).map_err(storage_error)?.is_some();
+        self.tree.flush().map_err(storage_error)?;
+        Ok(removed)
+    }
+
+    pub fn values<T: DeserializeOwned>(&self) -> Result<Vec<T>, SolanaError> {
+        self.tree
+            .iter()
+            .values()
+            .map(|value| {
+                let value = value.map_err(storage_error)?;
+                serde_json::from_slice(&value).map_err(storage_error)
+            })
+            .collect()
+    }
+}